@@ -1,8 +1,12 @@
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use filetime::FileTime;
+use sha2::{Digest, Sha256};
+
 fn main() {
     // Get the crate's manifest directory for resolving relative paths
     let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
@@ -11,7 +15,6 @@ fn main() {
     let hermes_home = env::var("HERMES_HOME")
         .map(PathBuf::from)
         .unwrap_or_else(|_| workspace_root.join("hermes"));
-    let hermes_build = hermes_home.join("build");
 
     // Always rerun build script to check if outputs exist
     println!("cargo:rerun-if-changed=build.rs");
@@ -20,35 +23,70 @@ fn main() {
     let dist_dir = workspace_root.join("dist");
     fs::create_dir_all(&dist_dir).expect("Failed to create dist directory");
 
-    // Bundle the Vue compiler JS (always run to ensure it's up to date)
-    let tools_dir = workspace_root.join("tools");
-    let bundle_status = Command::new("node")
-        .args(["--experimental-strip-types", "--no-warnings", "bundle.ts"])
-        .current_dir(&tools_dir)
-        .status()
-        .expect("Failed to run bundle.ts");
-    if !bundle_status.success() {
-        panic!("Failed to bundle Vue compiler JS");
-    }
+    let host = env::var("HOST").unwrap();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    let target = env::var("TARGET").unwrap();
+
+    // Prefer a prebuilt `vue-compiler.o` and Hermes libs over the local
+    // node/shermes/cmake toolchain, when one is available for download.
+    let prebuilt = download_prebuilt(&dist_dir, &target);
 
-    // Compile JS to native object with Static Hermes
-    let shermes = hermes_build.join("bin/shermes");
+    let tools_dir = workspace_root.join("tools");
     let vue_compiler_o = dist_dir.join("vue-compiler.o");
-    let vue_compiler_js = dist_dir.join("vue-compiler.js");
-    let shermes_status = Command::new(&shermes)
-        .args([
-            "-O", "-c", "-exported-unit=vue_compiler",
-            "-o", vue_compiler_o.to_str().unwrap(),
-            vue_compiler_js.to_str().unwrap(),
-        ])
-        .status()
-        .expect("Failed to run shermes");
-    if !shermes_status.success() {
-        panic!("Failed to compile Vue compiler with shermes");
-    }
+
+    let hermes_build = if let Some(prebuilt) = &prebuilt {
+        prebuilt.lib_dir.clone()
+    } else {
+        // Bundle the Vue compiler JS, unless the bundle is already up to date
+        let vue_compiler_js = dist_dir.join("vue-compiler.js");
+        let bundle_inputs = [
+            tools_dir.join("bundle.ts"),
+            tools_dir.join("package.json"),
+            manifest_dir.join("ffi/js/vue_compiler_sfc_bridge.js"),
+        ];
+        if !up_to_date(&bundle_inputs, &vue_compiler_js) {
+            let bundle_status = Command::new("node")
+                .args(["--experimental-strip-types", "--no-warnings", "bundle.ts"])
+                .current_dir(&tools_dir)
+                .status()
+                .expect("Failed to run bundle.ts");
+            if !bundle_status.success() {
+                panic!("Failed to bundle Vue compiler JS");
+            }
+        }
+
+        // `shermes` is a build-time tool that runs on this machine, so it's
+        // always built for/located on the host - never `target` - even
+        // though the Hermes libs below are cross-compiled for `target`.
+        let shermes = locate_host_shermes(&hermes_home, &host);
+        let hermes_build = find_or_build_hermes_libs(&hermes_home, &host, &target);
+
+        // Compile JS to native object with Static Hermes, unless it's already up to date
+        if !up_to_date(&[vue_compiler_js.clone()], &vue_compiler_o) {
+            let mut shermes_args = vec!["-c".to_string(), "-exported-unit=vue_compiler".to_string()];
+            if shermes_optimize() {
+                shermes_args.push("-O".to_string());
+            }
+            shermes_args.push(format!("-target={target}"));
+            shermes_args.push("-o".to_string());
+            shermes_args.push(vue_compiler_o.to_str().unwrap().to_string());
+            shermes_args.push(vue_compiler_js.to_str().unwrap().to_string());
+
+            let shermes_status = Command::new(&shermes)
+                .args(&shermes_args)
+                .status()
+                .expect("Failed to run shermes");
+            if !shermes_status.success() {
+                panic!("Failed to compile Vue compiler with shermes");
+            }
+        }
+
+        hermes_build
+    };
 
     // Compile the C++ wrapper
-    cc::Build::new()
+    let mut cpp_build = cc::Build::new();
+    cpp_build
         .cpp(true)
         .file(manifest_dir.join("ffi/cpp/runtime.cpp"))
         .file(manifest_dir.join("ffi/cpp/vue_sfc.cpp"))
@@ -57,9 +95,13 @@ fn main() {
         .include(hermes_home.join("API/jsi"))
         .include(hermes_home.join("include"))
         .include(hermes_home.join("public"))
-        .include(hermes_build.join("lib/config"))
-        .flag("-std=c++17")
-        .compile("wrapper");
+        .include(hermes_build.join("lib/config"));
+    if cpp_build.get_compiler().is_like_msvc() {
+        cpp_build.flag("/std:c++17");
+    } else {
+        cpp_build.flag("-std=c++17");
+    }
+    cpp_build.compile("wrapper");
 
     // Link the compiled Vue compiler object
     println!("cargo:rustc-link-arg={}", vue_compiler_o.display());
@@ -76,9 +118,22 @@ fn main() {
     println!("cargo:rustc-link-lib=static=jsi");
     println!("cargo:rustc-link-lib=static=boost_context");
 
-    // Link system libraries
-    println!("cargo:rustc-link-lib=c++");
-    println!("cargo:rustc-link-lib=framework=Foundation");
+    // Link the platform's C++ runtime and any OS services Hermes depends on
+    match target_os.as_str() {
+        "macos" | "ios" => {
+            println!("cargo:rustc-link-lib=c++");
+            println!("cargo:rustc-link-lib=framework=Foundation");
+        }
+        "windows" => {
+            // MSVC's and MinGW's C++ runtimes are linked in automatically.
+        }
+        _ => {
+            println!("cargo:rustc-link-lib=stdc++");
+            println!("cargo:rustc-link-lib=dl");
+            println!("cargo:rustc-link-lib=pthread");
+            println!("cargo:rustc-link-lib=m");
+        }
+    }
 
     // Rerun if sources change
     println!("cargo:rerun-if-changed={}", manifest_dir.join("ffi/cpp/runtime.h").display());
@@ -89,4 +144,206 @@ fn main() {
     println!("cargo:rerun-if-changed={}", manifest_dir.join("ffi/js/vue_compiler_sfc_bridge.js").display());
     println!("cargo:rerun-if-changed={}", tools_dir.join("bundle.ts").display());
     println!("cargo:rerun-if-changed={}", tools_dir.join("package.json").display());
+    println!("cargo:rerun-if-changed={}", hermes_home.join("API").display());
+    println!("cargo:rerun-if-changed={}", hermes_home.join("include").display());
+    println!("cargo:rerun-if-changed={}", hermes_home.join("lib").display());
+
+    // Rerun if the env vars that steer these build choices change: Cargo
+    // only auto-tracks env vars read when no explicit `rerun-if-*` is
+    // emitted, and we emit plenty above, so these need to be declared.
+    println!("cargo:rerun-if-env-changed=VUERS_BUILD_HERMES");
+    println!("cargo:rerun-if-env-changed=VUERS_PREBUILT_URL");
+}
+
+/// A prebuilt `vue-compiler.o` plus the static Hermes archives it links
+/// against, fetched by [`download_prebuilt`].
+struct Prebuilt {
+    /// Root of the unpacked tarball, laid out exactly like a local Hermes
+    /// build tree (`lib/`, `lib/config/`, `jsi/`, `tools/shermes/`,
+    /// `external/boost/.../context/`) so the rest of `main` can treat it
+    /// exactly like [`find_or_build_hermes_libs`]'s return value.
+    lib_dir: PathBuf,
+}
+
+/// SHA-256 digests of the release tarball, keyed by target triple. Entries
+/// are added as prebuilt artifacts are published for a given triple; an
+/// unlisted triple simply falls back to a local build.
+fn expected_sha256(target: &str) -> Option<&'static str> {
+    match target {
+        // "x86_64-unknown-linux-gnu" => Some("..."),
+        // "aarch64-apple-darwin" => Some("..."),
+        _ => {
+            let _ = target;
+            None
+        }
+    }
+}
+
+/// Downloads and unpacks a prebuilt `vue-compiler.o` + Hermes libs tarball
+/// for `target`, mirroring rustbuild's `download.rs`: resolve the URL
+/// (`VUERS_PREBUILT_URL`, or a default release URL keyed on the crate
+/// version and target triple), fetch it, verify it against an embedded
+/// SHA-256, and unpack it into `dist_dir`. Returns `None` - so callers fall
+/// back to a local source build - whenever downloading is disabled, no
+/// checksum is known for `target`, or the fetch/verification fails.
+fn download_prebuilt(dist_dir: &Path, target: &str) -> Option<Prebuilt> {
+    let sha256 = expected_sha256(target)?;
+
+    let url = env::var("VUERS_PREBUILT_URL").unwrap_or_else(|_| {
+        format!(
+            "https://github.com/hyf0/vuers/releases/download/v{}/vue-compiler-sfc-prebuilt-{}.tar.gz",
+            env::var("CARGO_PKG_VERSION").unwrap(),
+            target,
+        )
+    });
+
+    let prebuilt_dir = dist_dir.join("prebuilt");
+    let vue_compiler_o = dist_dir.join("vue-compiler.o");
+    let marker = prebuilt_dir.join(".sha256");
+    if vue_compiler_o.exists()
+        && marker.exists()
+        && fs::read_to_string(&marker).ok().as_deref() == Some(sha256)
+    {
+        return Some(Prebuilt { lib_dir: prebuilt_dir });
+    }
+
+    let archive = match ureq::get(&url).call() {
+        Ok(response) => {
+            let mut bytes = Vec::new();
+            if response.into_reader().read_to_end(&mut bytes).is_err() {
+                return None;
+            }
+            bytes
+        }
+        Err(_) => return None,
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(&archive);
+    if format!("{:x}", hasher.finalize()) != sha256 {
+        eprintln!("cargo:warning=prebuilt artifact checksum mismatch for {target}, building from source");
+        return None;
+    }
+
+    let _ = fs::remove_dir_all(&prebuilt_dir);
+    fs::create_dir_all(&prebuilt_dir).ok()?;
+    let tar = flate2::read::GzDecoder::new(&archive[..]);
+    tar::Archive::new(tar).unpack(&prebuilt_dir).ok()?;
+
+    // The tarball places `vue-compiler.o` alongside the Hermes tree; move it
+    // into `dist/` where the rest of `main` expects to find it.
+    fs::rename(prebuilt_dir.join("vue-compiler.o"), &vue_compiler_o).ok()?;
+    fs::write(&marker, sha256).ok()?;
+
+    Some(Prebuilt { lib_dir: prebuilt_dir })
+}
+
+/// Checks whether `output` is newer than every path in `inputs`, so the step
+/// that produces it can be skipped. Returns `false` (i.e. "needs rebuild")
+/// if `output` is missing or any input's mtime can't be read.
+fn up_to_date(inputs: &[PathBuf], output: &Path) -> bool {
+    let Ok(output_mtime) = fs::metadata(output).map(|m| FileTime::from_last_modification_time(&m))
+    else {
+        return false;
+    };
+    inputs.iter().all(|input| {
+        fs::metadata(input)
+            .map(|m| FileTime::from_last_modification_time(&m) <= output_mtime)
+            .unwrap_or(false)
+    })
+}
+
+/// Whether to pass `-O` to `shermes`, mirroring the crate's own
+/// optimization level: on for `--release` (or any explicit `OPT_LEVEL`
+/// above `0`), off for a plain debug build, so a debug build of this crate
+/// gets a faster-to-compile, unoptimized Vue compiler object.
+fn shermes_optimize() -> bool {
+    let profile = env::var("PROFILE").unwrap_or_else(|_| "debug".to_string());
+    let opt_level = env::var("OPT_LEVEL").unwrap_or_default();
+    profile == "release" || !matches!(opt_level.as_str(), "" | "0")
+}
+
+/// Path to the `shermes` binary within a Hermes build directory, relative to
+/// its root, for the given OS name (one of `CARGO_CFG_TARGET_OS`'s values).
+fn shermes_bin_name(os: &str) -> &'static str {
+    if os == "windows" {
+        "bin/shermes.exe"
+    } else {
+        "bin/shermes"
+    }
+}
+
+/// Coarse OS name for a `rustc`-style target triple, good enough to pick
+/// between [`shermes_bin_name`]'s two cases.
+fn os_from_triple(triple: &str) -> &'static str {
+    if triple.contains("windows") {
+        "windows"
+    } else if triple.contains("apple") {
+        "macos"
+    } else {
+        "linux"
+    }
+}
+
+/// Locates (or builds, via the `cmake` crate) the `shermes` tool itself.
+///
+/// `shermes` is invoked *during this build*, on this machine, to compile
+/// `vue-compiler.js` - so unlike [`find_or_build_hermes_libs`], it must
+/// always target `host`, never `CARGO_CFG_TARGET_OS`. Conflating the two
+/// would produce a `shermes` that can't run here whenever cross-compiling.
+fn locate_host_shermes(hermes_home: &Path, host: &str) -> PathBuf {
+    let host_os = os_from_triple(host);
+    let force_build = env::var_os("VUERS_BUILD_HERMES").is_some();
+
+    let prebuilt = hermes_home.join("build").join(shermes_bin_name(host_os));
+    if !force_build && prebuilt.exists() {
+        return prebuilt;
+    }
+
+    let host_build_dir = hermes_home.join("build-host");
+    let host_build = host_build_dir.join(shermes_bin_name(host_os));
+    if !force_build && host_build.exists() {
+        return host_build;
+    }
+
+    let mut config = cmake::Config::new(hermes_home);
+    config
+        .profile(if shermes_optimize() { "Release" } else { "Debug" })
+        .out_dir(&host_build_dir)
+        .build_target("shermes");
+    // No `CMAKE_TOOLCHAIN_FILE`/`CMAKE_SYSTEM_NAME` here: this configures
+    // and builds natively for the machine running the build script.
+    config.build().join(shermes_bin_name(host_os))
+}
+
+/// Locates a prebuilt Hermes tree at `<hermes_home>/build`, or builds the
+/// target-architecture Hermes static libs from source via the `cmake` crate
+/// when none is found (or when `VUERS_BUILD_HERMES` is set), returning the
+/// root of the resulting build directory either way.
+///
+/// Only the libs this crate links against are built: `hermesvm_a`, `jsi`,
+/// `shermes_console_a`, and `boost_context`. The `shermes` *tool* is built
+/// separately by [`locate_host_shermes`], for `host` rather than `target`.
+fn find_or_build_hermes_libs(hermes_home: &Path, host: &str, target: &str) -> PathBuf {
+    let prebuilt = hermes_home.join("build");
+    let force_build = env::var_os("VUERS_BUILD_HERMES").is_some();
+
+    if !force_build && prebuilt.join("lib").exists() {
+        return prebuilt;
+    }
+
+    let mut config = cmake::Config::new(hermes_home);
+    config.profile(if shermes_optimize() { "Release" } else { "Debug" });
+    if target != host {
+        // Cross-compiling the target-architecture libs: point CMake at a
+        // toolchain file named after the target triple, the convention
+        // Hermes' own cross-compile docs use for `CMAKE_TOOLCHAIN_FILE`.
+        config.define("CMAKE_TOOLCHAIN_FILE", hermes_home.join(format!("cmake/{target}.cmake")));
+    }
+
+    let mut dest = None;
+    for lib_target in ["hermesvm_a", "jsi", "shermes_console_a", "boost_context"] {
+        dest = Some(config.build_target(lib_target).build());
+    }
+    dest.expect("at least one Hermes lib target to build")
 }
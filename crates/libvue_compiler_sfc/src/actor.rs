@@ -0,0 +1,107 @@
+//! A `CompilerPool` pinned to exactly one dedicated worker thread, for
+//! callers that want a single serialized Hermes runtime reachable from many
+//! threads rather than a load-balanced pool.
+//!
+//! [`CompilerActor::spawn`] starts the worker and hands back a
+//! [`CompilerHandle`]: a cheap, `Clone`-able, `Send + Sync` front end - an
+//! `Arc` around the pool, the same "cheap handle shared across producers,
+//! one consumer drains it" shape `std::sync::mpsc::Sender` itself uses.
+//! Every method sends an owned request over the channel [`crate::pool`]
+//! already builds and blocks for an owned reply (`String`s and
+//! `Vec<Diagnostic>`, never a raw handle); the worker thread is the only
+//! place a `Compiler` or any lifetime-bound output type ever exists.
+
+use std::sync::Arc;
+
+use crate::compiler::Compiler;
+use crate::pool::{CompiledOutput, CompilerPool, SfcInput};
+use crate::types::{Diagnostic, Result};
+use crate::CachedCompile;
+
+/// Spawns the dedicated compiler worker thread.
+pub struct CompilerActor;
+
+impl CompilerActor {
+    /// Spawns the worker thread - lazily, on first use, same as
+    /// [`CompilerPool`] - and returns a [`CompilerHandle`] to it.
+    pub fn spawn() -> CompilerHandle {
+        CompilerHandle(Arc::new(CompilerPool::new(1)))
+    }
+}
+
+/// A cheap, `Clone`-able handle to a single [`CompilerActor`] worker thread.
+///
+/// Cloning shares the same underlying worker: every clone submits work to
+/// the same serialized Hermes runtime. [`Self::shutdown`] drops this
+/// handle's reference; once the last `CompilerHandle` to a worker is
+/// dropped, the pool's `Drop` closes its job queue and joins the worker
+/// thread, which frees any handles the runtime is still holding before the
+/// thread exits.
+#[derive(Clone)]
+pub struct CompilerHandle(Arc<CompilerPool>);
+
+impl CompilerHandle {
+    /// Runs `f` with the actor's `Compiler`, blocking until it completes.
+    pub fn scope<T>(&self, f: impl FnOnce(&Compiler) -> T + Send + 'static) -> Result<T>
+    where
+        T: Send + 'static,
+    {
+        self.0.scope(f)
+    }
+
+    /// Parses an SFC on the worker thread.
+    pub fn parse(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+    ) -> Result<Vec<Diagnostic>> {
+        self.0.parse(source, filename)
+    }
+
+    /// Compiles the script block(s) of an SFC on the worker thread.
+    pub fn compile_script(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+        id: impl Into<String>,
+        is_prod: bool,
+    ) -> Result<CompiledOutput> {
+        self.0.compile_script(source, filename, id, is_prod)
+    }
+
+    /// Compiles a template block on the worker thread.
+    pub fn compile_template(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+        id: impl Into<String>,
+        scoped: bool,
+    ) -> Result<CompiledOutput> {
+        self.0.compile_template(source, filename, id, scoped)
+    }
+
+    /// Compiles a style block on the worker thread.
+    pub fn compile_style(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+        id: impl Into<String>,
+        scoped: bool,
+    ) -> Result<CompiledOutput> {
+        self.0.compile_style(source, filename, id, scoped)
+    }
+
+    /// Fully compiles one SFC (script, template, and styles) on the worker
+    /// thread, returning an owned, handle-free [`CachedCompile`].
+    pub fn compile_sfc(&self, input: SfcInput) -> Result<CachedCompile> {
+        self.0.compile_sfc(input)
+    }
+
+    /// Drops this handle's reference to the worker. In-flight requests
+    /// already queued are still drained and replied to; once every
+    /// `CompilerHandle` clone has been dropped, the worker thread exits and
+    /// frees its runtime.
+    pub fn shutdown(self) {
+        drop(self);
+    }
+}
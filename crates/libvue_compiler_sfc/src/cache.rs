@@ -0,0 +1,195 @@
+//! Content-addressed compile cache for [`crate::Compiler`].
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+
+use crate::types::{Diagnostic, Result};
+
+/// Owned result of caching a full SFC compile: every string a compile of
+/// `compile_cached`'s source can produce, plus the diagnostics gathered
+/// from parsing and from each block that was compiled.
+#[derive(Debug, Clone, Default)]
+pub struct CachedCompile {
+    /// Compiled `<script>`/`<script setup>` content, if present.
+    pub script: Option<String>,
+    /// Compiled render function code, if a `<template>` block was present.
+    pub template: Option<String>,
+    /// Compiled CSS for each `<style>` block, in document order.
+    pub styles: Vec<String>,
+    /// Diagnostics gathered from parsing and from every block compiled.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A small FxHash-style hasher, the same family rustc and ahash use for
+/// hash maps keyed by short strings: fast and well-distributed, but not
+/// cryptographic. `HashMap` still compares full keys on a bucket match, so
+/// a weaker hash only costs a few extra equality checks, never correctness.
+#[derive(Default)]
+pub(crate) struct FxHasher(u64);
+
+const FX_SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0.rotate_left(5) ^ byte as u64).wrapping_mul(FX_SEED);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+pub(crate) type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// The cache key for a `compile_cached` call: everything that affects its
+/// output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+    pub source: String,
+    pub filename: String,
+    pub id: String,
+    pub is_prod: bool,
+    pub scoped: bool,
+}
+
+/// An LRU-bounded content-addressed cache from `K` to `V`.
+pub(crate) struct Cache<K, V> {
+    capacity: usize,
+    entries: HashMap<K, V, FxBuildHasher>,
+    // Most-recently-used key is at the back; `capacity` is small enough in
+    // practice (a build tool's working set, not a web cache) that a linear
+    // scan to move an entry to the back is cheaper than an intrusive list.
+    order: VecDeque<K>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Cache<K, V> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            entries: HashMap::with_capacity_and_hasher(capacity, FxBuildHasher::default()),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.entries.get(key).cloned()?;
+        self.touch(key);
+        Some(value)
+    }
+
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+            self.entries.insert(key, value);
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.order.push_back(key.clone());
+        self.entries.insert(key, value);
+    }
+
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let key = self.order.remove(pos).expect("position was just found");
+            self.order.push_back(key);
+        }
+    }
+
+    /// Evicts every entry whose key matches `predicate`.
+    pub(crate) fn invalidate_where(&mut self, predicate: impl Fn(&K) -> bool) {
+        self.entries.retain(|key, _| !predicate(key));
+        self.order.retain(|key| !predicate(key));
+    }
+
+    /// Evicts every entry.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+/// A content hash identifying a `(source, filename, options)` triple: the
+/// key [`CompileCache`] memoizes on.
+///
+/// Unlike [`CacheKey`] (a full structural key compared on every bucket
+/// match), this is a bare hash - `TemplateOptions`/`StyleOptions` borrow
+/// bindings that can't themselves be stored or compared cheaply, so a
+/// fingerprint is all a generic cache can key on. Collisions are
+/// astronomically unlikely at build-tool scale and the same trade-off Rhai
+/// makes with its seeded `AHASH_SEED` function-resolution cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// Hashes `source`, `filename`, and any `Hash`-able compile options into
+    /// a single fingerprint.
+    pub fn new(source: &str, filename: &str, options: impl Hash) -> Self {
+        let mut hasher = FxHasher::default();
+        source.hash(&mut hasher);
+        filename.hash(&mut hasher);
+        options.hash(&mut hasher);
+        Fingerprint(hasher.finish())
+    }
+}
+
+/// A general-purpose, opt-in, LRU-bounded cache from a [`Fingerprint`] to an
+/// owned compile result `V`, such as [`crate::TemplateSnapshot`] or
+/// [`crate::StyleSnapshot`].
+///
+/// Where [`crate::Compiler::with_cache`] memoizes a fixed `CachedCompile`
+/// shape for whole-SFC compiles, `CompileCache` is generic: build one per
+/// output type you want memoized across an iterative build, and look it up
+/// with [`Self::get_or_compile`].
+pub struct CompileCache<V: Clone> {
+    inner: RefCell<Cache<Fingerprint, V>>,
+}
+
+impl<V: Clone> CompileCache<V> {
+    /// Creates a cache holding up to `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: RefCell::new(Cache::new(capacity)),
+        }
+    }
+
+    /// Returns the cached value for `(source, filename, options)` if
+    /// present, otherwise runs `compile`, caches its result, and returns it.
+    pub fn get_or_compile(
+        &self,
+        source: &str,
+        filename: &str,
+        options: impl Hash,
+        compile: impl FnOnce() -> Result<V>,
+    ) -> Result<V> {
+        let key = Fingerprint::new(source, filename, options);
+
+        if let Some(hit) = self.inner.borrow_mut().get(&key) {
+            return Ok(hit);
+        }
+
+        let value = compile()?;
+        self.inner.borrow_mut().insert(key, value.clone());
+        Ok(value)
+    }
+
+    /// Evicts every cached entry.
+    ///
+    /// Unlike [`Compiler::invalidate`][crate::Compiler::invalidate], there's
+    /// no selective form here: a [`Fingerprint`] is a bare hash with no
+    /// filename to match against, so an editor/bundler reacting to a single
+    /// changed file can only clear the whole cache, not just that file's
+    /// entries.
+    pub fn clear(&self) {
+        self.inner.borrow_mut().clear();
+    }
+}
@@ -0,0 +1,443 @@
+//! Vue SFC Compiler instance.
+//!
+//! Each `Compiler` owns its own Hermes runtime and can be used independently.
+//! This enables thread-safe parallel compilation by creating one Compiler per thread.
+
+use std::cell::RefCell;
+use std::os::raw::c_char;
+
+use crate::cache::{Cache, CacheKey};
+use crate::ffi::{self, HermesHandle, HermesRuntime};
+use crate::types::{
+    CompileOptions, Error, ParseOptions, ParseOutput, Result, ScriptOptions, ScriptOutput,
+    StyleOptions, StyleOutput, TemplateOptions, TemplateOutput, Whitespace,
+};
+use crate::CachedCompile;
+
+/// Vue SFC compiler instance.
+///
+/// Each `Compiler` owns its own Hermes runtime and handle table. It is not
+/// `Send` or `Sync`: the runtime it wraps may only ever be touched from the
+/// thread that created it.
+///
+/// # Example
+///
+/// ```ignore
+/// use libvue_compiler_sfc::Compiler;
+///
+/// let compiler = Compiler::new()?;
+/// let parsed = compiler.parse(source, "App.vue")?;
+/// let desc = parsed.descriptor()?;
+/// ```
+///
+/// # Thread Safety
+///
+/// A single `Compiler` instance must only be used from one thread at a time.
+/// To compile in parallel, create multiple `Compiler` instances.
+pub struct Compiler {
+    pub(crate) runtime: HermesRuntime,
+    cache: Option<RefCell<Cache<CacheKey, CachedCompile>>>,
+}
+
+impl Compiler {
+    /// Creates a new compiler instance.
+    ///
+    /// This initializes a fresh Hermes runtime. The operation is relatively
+    /// expensive (~100ms), so reuse compiler instances when possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Hermes runtime fails to initialize.
+    pub fn new() -> Result<Self> {
+        let runtime = unsafe { ffi::hermes_runtime_create() };
+        if runtime.is_null() {
+            return Err(Error::new("Failed to create compiler instance"));
+        }
+        Ok(Self {
+            runtime,
+            cache: None,
+        })
+    }
+
+    /// Creates a new compiler instance with an opt-in, LRU-bounded compile
+    /// cache holding up to `capacity` entries.
+    ///
+    /// [`Self::compile_cached`] keys each call on a fast hash of
+    /// `(source, filename, id, is_prod, scoped)` and stores the owned
+    /// compiled output, so recompiling an unchanged file - the common case
+    /// during watch-mode rebuilds - skips the Hermes round-trip entirely.
+    pub fn with_cache(capacity: usize) -> Result<Self> {
+        let mut compiler = Self::new()?;
+        compiler.cache = Some(RefCell::new(Cache::new(capacity)));
+        Ok(compiler)
+    }
+
+    /// Compiles a full SFC - script, template, and styles - returning a
+    /// clone of the cached result if this exact `(source, filename, id,
+    /// is_prod, scoped)` combination was already compiled and this
+    /// `Compiler` was created with [`Self::with_cache`]. Without a cache,
+    /// behaves the same but always recompiles.
+    pub fn compile_cached(
+        &self,
+        source: &str,
+        filename: &str,
+        id: &str,
+        is_prod: bool,
+        scoped: bool,
+    ) -> Result<CachedCompile> {
+        let key = CacheKey {
+            source: source.to_string(),
+            filename: filename.to_string(),
+            id: id.to_string(),
+            is_prod,
+            scoped,
+        };
+
+        if let Some(cache) = &self.cache {
+            if let Some(hit) = cache.borrow_mut().get(&key) {
+                return Ok(hit);
+            }
+        }
+
+        let result = self.compile_uncached(source, filename, id, is_prod, scoped)?;
+
+        if let Some(cache) = &self.cache {
+            cache.borrow_mut().insert(key, result.clone());
+        }
+
+        Ok(result)
+    }
+
+    /// Evicts every cached compile for `filename` from this `Compiler`'s
+    /// [`Self::with_cache`] cache, if it has one. Call this when `filename`
+    /// changes on disk so the next [`Self::compile_cached`] call for it
+    /// recompiles instead of returning stale output.
+    pub fn invalidate(&self, filename: &str) {
+        if let Some(cache) = &self.cache {
+            cache
+                .borrow_mut()
+                .invalidate_where(|key| key.filename == filename);
+        }
+    }
+
+    /// Evicts every cached compile from this `Compiler`'s [`Self::with_cache`]
+    /// cache, if it has one.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.borrow_mut().clear();
+        }
+    }
+
+    fn compile_uncached(
+        &self,
+        source: &str,
+        filename: &str,
+        id: &str,
+        is_prod: bool,
+        scoped: bool,
+    ) -> Result<CachedCompile> {
+        let parsed = self.parse(source, filename)?;
+        let mut diagnostics = parsed.diagnostics();
+
+        let Some(descriptor) = parsed.descriptor() else {
+            return Ok(CachedCompile {
+                diagnostics,
+                ..Default::default()
+            });
+        };
+
+        let script_output = if descriptor.has_script() || descriptor.has_script_setup() {
+            Some(descriptor.compile_script(id, is_prod)?)
+        } else {
+            None
+        };
+        if let Some(output) = &script_output {
+            diagnostics.extend(output.diagnostics());
+        }
+
+        let template = match descriptor.template() {
+            Some(template) => {
+                let output = self.compile_template(
+                    template.content(),
+                    filename,
+                    id,
+                    scoped,
+                    script_output.as_ref(),
+                )?;
+                diagnostics.extend(output.diagnostics());
+                Some(output.code().to_string())
+            }
+            None => None,
+        };
+
+        let styles = descriptor
+            .styles()
+            .map(|style| {
+                let output = self.compile_style(style.content(), filename, id, style.is_scoped())?;
+                diagnostics.extend(output.diagnostics());
+                Ok(output.code().to_string())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(CachedCompile {
+            script: script_output.map(|output| output.content().to_string()),
+            template,
+            styles,
+            diagnostics,
+        })
+    }
+
+    /// Parses a Vue Single File Component source string.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The SFC source code as a string.
+    /// * `filename` - The filename (used for error messages and source maps).
+    pub fn parse<'c>(&'c self, source: &str, filename: &str) -> Result<ParseOutput<'c>> {
+        self.parse_with(source, filename, &ParseOptions::default())
+    }
+
+    /// Parses a Vue Single File Component source string using a
+    /// [`ParseOptions`] builder, for the `source_map`/`ignore_empty`/
+    /// `whitespace`/`pad` flags [`Self::parse`]'s fixed positional
+    /// arguments have no room for.
+    pub fn parse_with<'c>(
+        &'c self,
+        source: &str,
+        filename: &str,
+        options: &ParseOptions,
+    ) -> Result<ParseOutput<'c>> {
+        let handle = unsafe {
+            ffi::vue_parse_with_options(
+                self.runtime,
+                source.as_ptr() as *const c_char,
+                source.len(),
+                filename.as_ptr() as *const c_char,
+                filename.len(),
+                options.source_map,
+                options.ignore_empty,
+                options.whitespace == Whitespace::Preserve,
+                options.pad,
+            )
+        };
+
+        if !handle.is_valid() {
+            return Err(Error::new("Parse returned invalid handle"));
+        }
+
+        Ok(ParseOutput::from_raw(handle, &self.runtime))
+    }
+
+    /// Compiles a Vue template to a render function.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The template source code.
+    /// * `filename` - The filename (for error messages).
+    /// * `id` - A unique scope ID for scoped CSS (e.g., "data-v-abc123").
+    /// * `scoped` - Whether to add scoped attribute selectors.
+    /// * `bindings` - Optional bindings from script compilation for optimization.
+    pub fn compile_template<'c>(
+        &'c self,
+        source: &str,
+        filename: &str,
+        id: &str,
+        scoped: bool,
+        bindings: Option<&ScriptOutput<'c>>,
+    ) -> Result<TemplateOutput<'c>> {
+        let mut options = TemplateOptions::new().filename(filename).scope_id(id).scoped(scoped);
+        if let Some(bindings) = bindings {
+            options = options.bindings(bindings);
+        }
+        self.compile_template_with(source, &options)
+    }
+
+    /// Compiles a Vue template using a [`TemplateOptions`] builder, for the
+    /// `ssr`/`prod`/`source_map`/`whitespace`/`inline_template` flags
+    /// [`Self::compile_template`]'s fixed positional arguments have no room
+    /// for.
+    pub fn compile_template_with<'c>(
+        &'c self,
+        source: &str,
+        options: &TemplateOptions<'c>,
+    ) -> Result<TemplateOutput<'c>> {
+        let bindings_handle = options
+            .bindings
+            .map(|b| b.bindings_handle())
+            .unwrap_or(HermesHandle::INVALID);
+
+        let handle = unsafe {
+            ffi::vue_compile_template_with_options(
+                self.runtime,
+                source.as_ptr() as *const c_char,
+                source.len(),
+                options.filename.as_ptr() as *const c_char,
+                options.filename.len(),
+                options.scope_id.as_ptr() as *const c_char,
+                options.scope_id.len(),
+                options.scoped,
+                options.ssr,
+                options.prod,
+                options.source_map,
+                options.whitespace == Whitespace::Preserve,
+                options.inline_template,
+                bindings_handle,
+            )
+        };
+
+        if !handle.is_valid() {
+            return Err(Error::new("compile_template returned invalid handle"));
+        }
+
+        Ok(TemplateOutput::from_raw(handle, &self.runtime))
+    }
+
+    /// Compiles a CSS style block.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The CSS source code.
+    /// * `filename` - The filename (for error messages).
+    /// * `id` - A unique scope ID for scoped CSS.
+    /// * `scoped` - Whether to add scoped attribute selectors.
+    pub fn compile_style<'c>(
+        &'c self,
+        source: &str,
+        filename: &str,
+        id: &str,
+        scoped: bool,
+    ) -> Result<StyleOutput<'c>> {
+        let options = StyleOptions::new().filename(filename).scope_id(id).scoped(scoped);
+        self.compile_style_with(source, &options)
+    }
+
+    /// Compiles a CSS style block using a [`StyleOptions`] builder, for the
+    /// `prod`/`source_map` flags [`Self::compile_style`]'s fixed positional
+    /// arguments have no room for.
+    pub fn compile_style_with<'c>(
+        &'c self,
+        source: &str,
+        options: &StyleOptions,
+    ) -> Result<StyleOutput<'c>> {
+        let handle = unsafe {
+            ffi::vue_compile_style_with_options(
+                self.runtime,
+                source.as_ptr() as *const c_char,
+                source.len(),
+                options.filename.as_ptr() as *const c_char,
+                options.filename.len(),
+                options.scope_id.as_ptr() as *const c_char,
+                options.scope_id.len(),
+                options.scoped,
+                options.prod,
+                options.source_map,
+            )
+        };
+
+        if !handle.is_valid() {
+            return Err(Error::new("compile_style returned invalid handle"));
+        }
+
+        Ok(StyleOutput::from_raw(handle, &self.runtime))
+    }
+
+    /// Compiles a full SFC - script, template, and styles - driven by a
+    /// [`CompileOptions`] builder, for the `ssr`/dev-vs-prod/`source_map`
+    /// flags [`Self::compile_cached`]'s fixed positional arguments have no
+    /// room for. Unlike [`Self::compile_cached`], this never consults or
+    /// populates the [`Self::with_cache`] cache: it exists for configurable
+    /// one-off compiles (e.g. an SSR build pass), not hot-path reuse.
+    pub fn compile_with(
+        &self,
+        source: &str,
+        filename: &str,
+        options: &CompileOptions,
+    ) -> Result<CachedCompile> {
+        let parsed = self.parse(source, filename)?;
+        let mut diagnostics = parsed.diagnostics();
+
+        let Some(descriptor) = parsed.descriptor() else {
+            return Ok(CachedCompile {
+                diagnostics,
+                ..Default::default()
+            });
+        };
+
+        let script_output = if descriptor.has_script() || descriptor.has_script_setup() {
+            let script_options = ScriptOptions::new()
+                .scope_id(options.scope_id.clone())
+                .prod(options.is_prod())
+                .source_map(options.source_map);
+            Some(descriptor.compile_script_with(&script_options)?)
+        } else {
+            None
+        };
+        if let Some(output) = &script_output {
+            diagnostics.extend(output.diagnostics());
+        }
+
+        let template = match descriptor.template() {
+            Some(template) => {
+                let mut template_options = TemplateOptions::new()
+                    .filename(filename)
+                    .scope_id(&options.scope_id)
+                    .scoped(options.scoped)
+                    .ssr(options.ssr)
+                    .prod(options.is_prod())
+                    .source_map(options.source_map);
+                if let Some(bindings) = script_output.as_ref() {
+                    template_options = template_options.bindings(bindings);
+                }
+                let output = self.compile_template_with(template.content(), &template_options)?;
+                diagnostics.extend(output.diagnostics());
+                Some(output.code().to_string())
+            }
+            None => None,
+        };
+
+        let styles = descriptor
+            .styles()
+            .map(|style| {
+                let style_options = StyleOptions::new()
+                    .filename(filename)
+                    .scope_id(&options.scope_id)
+                    .scoped(style.is_scoped())
+                    .prod(options.is_prod())
+                    .source_map(options.source_map);
+                let output = self.compile_style_with(style.content(), &style_options)?;
+                diagnostics.extend(output.diagnostics());
+                Ok(output.code().to_string())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let script = script_output.map(|output| match &options.import_map {
+            Some(import_map) => import_map.rewrite(output.content()),
+            None => output.content().to_string(),
+        });
+
+        Ok(CachedCompile {
+            script,
+            template,
+            styles,
+            diagnostics,
+        })
+    }
+
+    /// Releases any handles the Hermes runtime has accumulated since the
+    /// last reset.
+    ///
+    /// Every `Output`/`Descriptor`/etc. handle this `Compiler` hands out
+    /// frees itself via `Drop`, but a long-lived runtime still accumulates
+    /// garbage as it compiles - harmless for a one-off `Compiler`, but
+    /// [`crate::CompilerPool`] reuses the same `Compiler` across thousands
+    /// of jobs, so it calls this between jobs to keep that growth bounded.
+    pub(crate) fn reset(&self) {
+        unsafe { ffi::hermes_runtime_collect_garbage(self.runtime) };
+    }
+}
+
+impl Drop for Compiler {
+    fn drop(&mut self) {
+        unsafe { ffi::hermes_runtime_destroy(self.runtime) };
+    }
+}
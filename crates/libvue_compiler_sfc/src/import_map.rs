@@ -0,0 +1,311 @@
+//! Import-map based specifier rewriting for compiled script output.
+//!
+//! [`ImportMap`] mirrors the browser import-map resolution algorithm: an
+//! exact specifier match wins outright, otherwise the longest
+//! trailing-slash-prefix entry that matches is used. [`ImportMap::rewrite`]
+//! applies that resolution to every static/dynamic import and re-export
+//! specifier found in a compiled script's source text, leaving everything
+//! else untouched - there is no AST to rewrite against once the script has
+//! left Hermes, so this works the same way a source-map remap does: as a
+//! text-level pass over the already-compiled output.
+
+use std::collections::HashMap;
+
+/// A specifier-prefix -> target resolution table, applied to compiled
+/// script output via [`ImportMap::rewrite`].
+#[derive(Debug, Clone, Default)]
+pub struct ImportMap {
+    entries: HashMap<String, String>,
+}
+
+impl ImportMap {
+    /// Starts an empty import map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a specifier-prefix -> target mapping. A key ending in `/`
+    /// matches any specifier sharing that prefix (longest prefix wins);
+    /// any other key matches only that exact specifier.
+    pub fn map(mut self, specifier: impl Into<String>, target: impl Into<String>) -> Self {
+        self.entries.insert(specifier.into(), target.into());
+        self
+    }
+
+    /// Resolves `specifier` against this map: exact match first, else the
+    /// longest trailing-slash prefix match, else `None` if nothing applies.
+    pub fn resolve(&self, specifier: &str) -> Option<String> {
+        if let Some(target) = self.entries.get(specifier) {
+            return Some(target.clone());
+        }
+
+        let mut best: Option<(&str, &str)> = None;
+        for (prefix, target) in &self.entries {
+            if !prefix.ends_with('/') || !specifier.starts_with(prefix.as_str()) {
+                continue;
+            }
+            if best.map_or(true, |(best_prefix, _)| prefix.len() > best_prefix.len()) {
+                best = Some((prefix, target));
+            }
+        }
+
+        best.map(|(prefix, target)| format!("{target}{}", &specifier[prefix.len()..]))
+    }
+
+    /// Rewrites every static/dynamic import and re-export specifier in
+    /// `code` that resolves against this map. Specifiers that don't match
+    /// any entry, and string literals that aren't import/export/`require`
+    /// specifiers, are copied through unchanged.
+    pub fn rewrite(&self, code: &str) -> String {
+        let mut out = String::with_capacity(code.len());
+        let mut rest = code;
+
+        while let Some(literal) = next_string_literal(rest) {
+            out.push_str(&rest[..literal.quote_start]);
+
+            let specifier = &rest[literal.content_start..literal.content_end];
+            let target = if literal.is_specifier_position(&rest[..literal.quote_start]) {
+                self.resolve(specifier)
+            } else {
+                None
+            };
+
+            match target {
+                Some(target) => {
+                    out.push(literal.quote);
+                    out.push_str(&target);
+                    out.push(literal.quote);
+                }
+                None => out.push_str(&rest[literal.quote_start..=literal.content_end]),
+            }
+
+            rest = &rest[literal.content_end + 1..];
+        }
+        out.push_str(rest);
+
+        out
+    }
+}
+
+struct StringLiteral {
+    quote: char,
+    quote_start: usize,
+    content_start: usize,
+    content_end: usize,
+}
+
+impl StringLiteral {
+    /// A quoted string only names an import/export specifier when it's
+    /// immediately preceded (modulo whitespace, and an opening `(` for
+    /// dynamic `import(...)`) by the whole keyword `from`, `import`, or
+    /// `require` - not just text that happens to end with those letters,
+    /// e.g. `_interopRequire(...)` or an identifier like `xrequire`.
+    fn is_specifier_position(&self, before: &str) -> bool {
+        let before = before.trim_end();
+        let before = before.strip_suffix('(').map(str::trim_end).unwrap_or(before);
+
+        ["from", "import", "require"].into_iter().any(|keyword| {
+            if !before.ends_with(keyword) {
+                return false;
+            }
+            match before[..before.len() - keyword.len()].chars().next_back() {
+                Some(c) => !c.is_alphanumeric() && c != '_',
+                None => true,
+            }
+        })
+    }
+}
+
+/// Finds the next top-level single- or double-quoted string literal in
+/// `code`, skipping escaped quotes. Backtick template literals (including
+/// any `${...}` interpolations they contain) and `//`/`/* */` comments are
+/// skipped over rather than scanned into: none of `from`/`import`/`require`
+/// take a template literal as a specifier, and a `'`/`"` inside either kind
+/// of span isn't a real string literal at all.
+fn next_string_literal(code: &str) -> Option<StringLiteral> {
+    let bytes = code.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let ch = bytes[i] as char;
+        match ch {
+            '\'' | '"' => {
+                let content_start = i + 1;
+                let mut j = content_start;
+                while j < bytes.len() {
+                    match bytes[j] as char {
+                        '\\' => j += 2,
+                        c if c == ch => {
+                            return Some(StringLiteral {
+                                quote: ch,
+                                quote_start: i,
+                                content_start,
+                                content_end: j,
+                            });
+                        }
+                        _ => j += 1,
+                    }
+                }
+                return None;
+            }
+            '`' => i = skip_template_literal(bytes, i),
+            '/' if bytes.get(i + 1) == Some(&b'/') => i = skip_line_comment(bytes, i),
+            '/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(bytes, i),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Skips a quoted string literal starting at `start` (which must point at
+/// the opening `'`/`"`), returning the index just past its closing quote,
+/// or `bytes.len()` if it's unterminated.
+fn skip_quoted(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start] as char;
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] as char {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Skips a `//` line comment starting at `start`, returning the index of
+/// the terminating newline, or `bytes.len()` if the comment runs to EOF.
+fn skip_line_comment(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 2;
+    while i < bytes.len() && bytes[i] as char != '\n' {
+        i += 1;
+    }
+    i
+}
+
+/// Skips a `/* ... */` block comment starting at `start`, returning the
+/// index just past the closing `*/`, or `bytes.len()` if it's unterminated.
+fn skip_block_comment(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 2;
+    while i + 1 < bytes.len() {
+        if bytes[i] as char == '*' && bytes[i + 1] as char == '/' {
+            return i + 2;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Skips a backtick template literal starting at `start` (which must point
+/// at the opening backtick), including any `${...}` interpolations -
+/// recursing into them for nested strings/templates/comments and tracking
+/// brace depth so a `}` inside a nested object literal doesn't end the
+/// interpolation early. Returns the index just past the closing backtick,
+/// or `bytes.len()` if it's unterminated.
+fn skip_template_literal(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] as char {
+            '\\' => i += 2,
+            '`' => return i + 1,
+            '$' if bytes.get(i + 1) == Some(&b'{') => {
+                i += 2;
+                let mut depth = 1;
+                while i < bytes.len() && depth > 0 {
+                    match bytes[i] as char {
+                        '\\' => i += 2,
+                        '\'' | '"' => i = skip_quoted(bytes, i),
+                        '`' => i = skip_template_literal(bytes, i),
+                        '/' if bytes.get(i + 1) == Some(&b'/') => i = skip_line_comment(bytes, i),
+                        '/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(bytes, i),
+                        '{' => {
+                            depth += 1;
+                            i += 1;
+                        }
+                        '}' => {
+                            depth -= 1;
+                            i += 1;
+                        }
+                        _ => i += 1,
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrite_resolves_exact_and_prefix_entries() {
+        let map = ImportMap::new()
+            .map("vue", "/vendor/vue.js")
+            .map("@scope/", "/vendor/scope/");
+
+        let code = r#"import { h } from 'vue';
+import { util } from "@scope/util";
+const dyn = import('vue');
+const req = require('vue');"#;
+
+        let out = map.rewrite(code);
+        assert!(out.contains("from '/vendor/vue.js'"));
+        assert!(out.contains("from \"/vendor/scope/util\""));
+        assert!(out.contains("import('/vendor/vue.js')"));
+        assert!(out.contains("require('/vendor/vue.js')"));
+    }
+
+    #[test]
+    fn rewrite_leaves_unmapped_and_non_specifier_strings_untouched() {
+        let map = ImportMap::new().map("vue", "/vendor/vue.js");
+
+        let code = r#"import { h } from 'react';
+const s = 'vue';"#;
+
+        assert_eq!(map.rewrite(code), code);
+    }
+
+    #[test]
+    fn rewrite_does_not_match_keyword_as_identifier_suffix() {
+        let map = ImportMap::new().map("vue", "/vendor/vue.js");
+
+        // `_interopRequire(...)` and `xrequire` both end in "require" but
+        // aren't the `require` keyword, so the string must pass through.
+        let code = r#"const a = _interopRequire('vue');
+const b = xrequire('vue');"#;
+
+        assert_eq!(map.rewrite(code), code);
+    }
+
+    #[test]
+    fn rewrite_skips_apostrophes_inside_template_literals() {
+        let map = ImportMap::new().map("vue", "/vendor/vue.js");
+
+        // The apostrophe in `it's` must not be mistaken for the start of a
+        // single-quoted string, which would otherwise swallow the real
+        // `from 'vue'` specifier that follows into a single bogus literal.
+        let code = "const msg = `it's a test`;\nimport { h } from 'vue';";
+
+        let out = map.rewrite(code);
+        assert!(out.contains("it's a test"));
+        assert!(out.contains("from '/vendor/vue.js'"));
+    }
+
+    #[test]
+    fn rewrite_skips_quotes_inside_template_interpolations_and_comments() {
+        let map = ImportMap::new().map("vue", "/vendor/vue.js");
+
+        let code = r#"const msg = `hello ${name || "fallback"}`;
+// from 'not-a-real-import'
+/* also not 'an import' */
+import { h } from 'vue';"#;
+
+        let out = map.rewrite(code);
+        assert!(out.contains(r#"`hello ${name || "fallback"}`"#));
+        assert!(out.contains("// from 'not-a-real-import'"));
+        assert!(out.contains("/* also not 'an import' */"));
+        assert!(out.contains("from '/vendor/vue.js'"));
+    }
+}
@@ -16,6 +16,16 @@
 //! - **`bindings`** (recommended): Safe Rust types with RAII and methods
 //! - **`ffi`**: Raw FFI bindings (unsafe, for advanced use)
 //!
+//! # Diagnostics
+//!
+//! `ParseOutput`, `ScriptOutput`, `TemplateOutput`, and `StyleOutput` all
+//! expose `diagnostics() -> Vec<Diagnostic>` alongside their plain
+//! `error_count()`/`has_errors()` flags. Each [`Diagnostic`] carries a
+//! `severity`, an optional machine-readable `code`, and an optional
+//! `loc: SourceLocation`; [`Diagnostic::render`] turns that into an
+//! annotated source snippet (gutter + caret underline), and
+//! [`Diagnostic::render_with_filename`] prefixes a `file:line:col` header.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -57,7 +67,13 @@
 pub use lib_vue_compiler_sfc_sys as ffi;
 
 // Layer 2: Safe Rust types and compiler
+mod actor;
+mod cache;
 mod compiler;
+mod import_map;
+mod pool;
+mod project;
+mod source_map;
 pub(crate) mod types;
 mod util;
 
@@ -66,9 +82,17 @@ mod util;
 mod tests;
 
 // Re-export public API
+pub use actor::{CompilerActor, CompilerHandle};
+pub use cache::{CachedCompile, CompileCache, Fingerprint};
 pub use compiler::Compiler;
+pub use import_map::ImportMap;
+pub use pool::{CompiledOutput, CompilerPool, SfcInput};
+pub use project::{CompiledComponent, Project, ProjectConfig};
+pub use source_map::{merge_source_maps, SourceMap};
 pub use types::{
-    AttrValue, CustomBlock, Descriptor, Error, ImportBinding, ParseOutput, Position, Result,
-    ScriptBlock, ScriptOutput, SourceLocation, StyleBlock, StyleOutput, TemplateBlock,
-    TemplateOutput,
+    AttrValue, BlockKind, CompileOptions, CustomBlock, DependencyKind, Descriptor, Diagnostic,
+    Error, ImportBinding, ModuleDependency, ParseOptions, ParseOutput, Position, Result,
+    ScriptBlock, ScriptOptions, ScriptOutput, Severity, SourceLocation, StyleBlock, StyleOptions,
+    StyleOutput, StyleSnapshot, TemplateBlock, TemplateOptions, TemplateOutput, TemplateSnapshot,
+    Whitespace,
 };
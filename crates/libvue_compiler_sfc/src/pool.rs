@@ -0,0 +1,442 @@
+//! Thread-safe compiler pool.
+//!
+//! `Compiler` is deliberately `!Send`/`!Sync`: it owns a Hermes runtime that
+//! may only ever be touched from the thread that created it. `CompilerPool`
+//! builds the `Send + Sync` story on top of that constraint by spawning
+//! worker threads, each with its own long-lived `Compiler`, and dispatching
+//! compile requests to them over a shared job queue - the same
+//! channel-plus-mutex-guarded-receiver shape [`crate::Project`] uses for its
+//! one-shot worker scopes, just kept alive for the pool's whole lifetime.
+//! Every method serializes its request onto the queue, blocks for the reply,
+//! and returns fully owned data: no lifetime-bound handle ever crosses a
+//! thread boundary.
+//!
+//! Since runtime creation dominates the cost of using a `Compiler` (~100ms),
+//! the pool grows lazily - spawning a worker (and its `Compiler`) only the
+//! first time demand needs one, up to a configured `max` - and then reuses
+//! those warm, idle workers for every later job, mirroring the
+//! "reuse warm state across repeated calls" optimization Rhai applies with
+//! its function-resolution cache.
+//!
+//! This deliberately does not hand out a `PooledCompiler` guard the caller
+//! holds directly: doing so would require `Compiler: Send`, which would
+//! undermine the single-runtime-per-thread invariant the rest of this crate
+//! depends on. [`CompilerPool::scope`] gives the same "run this with a warm
+//! compiler" ergonomics by running the closure *on* a pooled worker thread
+//! instead of moving the `Compiler` to the caller.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::compiler::Compiler;
+use crate::types::{Diagnostic, Error, Result};
+use crate::CachedCompile;
+
+/// Owned result of compiling a script, template, or style block: the
+/// generated code, its merged source map (if source maps were enabled),
+/// and any diagnostics the compile produced.
+#[derive(Debug, Clone)]
+pub struct CompiledOutput {
+    /// The generated code (script content or rendered template/CSS).
+    pub code: String,
+    /// The Source Map V3 JSON for this output, if source maps were enabled.
+    pub source_map: Option<String>,
+    /// Diagnostics produced while compiling this block.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// One SFC to compile via [`CompilerPool::compile_all`].
+#[derive(Debug, Clone)]
+pub struct SfcInput {
+    /// The SFC source code.
+    pub source: String,
+    /// The filename (used for error messages and source maps).
+    pub filename: String,
+    /// A unique scope ID for scoped CSS (e.g. `"data-v-abc123"`).
+    pub id: String,
+    /// Whether to compile for production (drops dev-only checks).
+    pub is_prod: bool,
+    /// Whether to add scoped attribute selectors.
+    pub scoped: bool,
+}
+
+enum Job {
+    Parse {
+        source: String,
+        filename: String,
+        reply: Sender<Result<Vec<Diagnostic>>>,
+    },
+    CompileScript {
+        source: String,
+        filename: String,
+        id: String,
+        is_prod: bool,
+        reply: Sender<Result<CompiledOutput>>,
+    },
+    CompileTemplate {
+        source: String,
+        filename: String,
+        id: String,
+        scoped: bool,
+        reply: Sender<Result<CompiledOutput>>,
+    },
+    CompileStyle {
+        source: String,
+        filename: String,
+        id: String,
+        scoped: bool,
+        reply: Sender<Result<CompiledOutput>>,
+    },
+    CompileSfc {
+        input: SfcInput,
+        reply: Sender<Result<CachedCompile>>,
+    },
+    Scope(Box<dyn FnOnce(&Compiler) + Send>),
+}
+
+/// A `Send + Sync` handle to a pool of dedicated compiler threads.
+///
+/// Clone-free: share one `CompilerPool` (e.g. behind an `Arc`) across as
+/// many caller threads as needed, and its requests will be load-balanced
+/// across the pool's workers.
+pub struct CompilerPool {
+    sender: Sender<Job>,
+    receiver: Arc<Mutex<Receiver<Job>>>,
+    max: usize,
+    spawned: Mutex<usize>,
+    workers: Mutex<Vec<thread::JoinHandle<()>>>,
+}
+
+impl CompilerPool {
+    /// Creates a pool that lazily grows to at most `max` worker threads,
+    /// each with its own `Compiler`.
+    ///
+    /// No threads are spawned up front; the first `max` jobs dispatched
+    /// concurrently each spawn one more warm worker, and every job after
+    /// that reuses whichever worker becomes free. `max` is clamped to at
+    /// least 1.
+    pub fn new(max: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+
+        CompilerPool {
+            sender,
+            receiver: Arc::new(Mutex::new(receiver)),
+            max: max.max(1),
+            spawned: Mutex::new(0),
+            workers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Runs `f` with a warm, pooled `Compiler`, blocking until it completes.
+    ///
+    /// Use this for anything [`Self::parse`]/[`Self::compile_script`]/
+    /// [`Self::compile_template`]/[`Self::compile_style`] don't already
+    /// cover, such as walking a `Descriptor` directly.
+    pub fn scope<T>(&self, f: impl FnOnce(&Compiler) -> T + Send + 'static) -> Result<T>
+    where
+        T: Send + 'static,
+    {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        self.dispatch_job(Job::Scope(Box::new(move |compiler| {
+            let _ = reply_tx.send(f(compiler));
+        })))?;
+        reply_rx
+            .recv()
+            .map_err(|_| Error::new("compiler pool worker terminated before replying"))
+    }
+
+    /// Parses every `(source, filename)` pair across the pool concurrently,
+    /// returning results in the same order as `sources`.
+    ///
+    /// Modeled on rustdoc's parallel renderer (and mirroring
+    /// [`crate::Project::compile_all_with`]): at most `self.max` caller
+    /// threads pull from a shared queue and funnel their results back,
+    /// rather than spawning one thread per source, so a large batch can't
+    /// exhaust OS thread limits.
+    pub fn par_parse<S, F>(
+        &self,
+        sources: impl IntoIterator<Item = (S, F)>,
+    ) -> Vec<Result<Vec<Diagnostic>>>
+    where
+        S: Into<String>,
+        F: Into<String>,
+    {
+        let sources: Vec<(String, String)> = sources
+            .into_iter()
+            .map(|(source, filename)| (source.into(), filename.into()))
+            .collect();
+
+        let placeholder = || Err(Error::new("par_parse: source was never scheduled"));
+        let results = Mutex::new(sources.iter().map(|_| placeholder()).collect::<Vec<_>>());
+        let worker_count = self.max.min(sources.len().max(1));
+        let queue = Mutex::new(sources.into_iter().enumerate().collect::<Vec<_>>());
+
+        thread::scope(|scope| {
+            for _ in 0..worker_count {
+                scope.spawn(|| loop {
+                    let next = queue.lock().expect("par_parse queue poisoned").pop();
+                    let Some((index, (source, filename))) = next else {
+                        break;
+                    };
+                    let result = self.parse(source, filename);
+                    results.lock().expect("par_parse results poisoned")[index] = result;
+                });
+            }
+        });
+
+        results.into_inner().expect("par_parse results poisoned")
+    }
+
+    /// Fully compiles one SFC (script, template, and styles) on a worker
+    /// thread, returning an owned, handle-free [`CachedCompile`].
+    pub fn compile_sfc(&self, input: SfcInput) -> Result<CachedCompile> {
+        self.dispatch(|reply| Job::CompileSfc { input, reply })
+    }
+
+    /// Compiles every input across the pool concurrently, returning results
+    /// in the same order as `inputs`.
+    ///
+    /// Modeled on rustdoc's parallel renderer (and mirroring
+    /// [`crate::Project::compile_all_with`]): at most `self.max` caller
+    /// threads pull from a shared queue and funnel their results back,
+    /// rather than spawning one thread per input, so a large project's
+    /// files stay load-balanced across cores without risking the OS thread
+    /// limit on a batch of hundreds or thousands of files.
+    pub fn compile_all(&self, inputs: impl IntoIterator<Item = SfcInput>) -> Vec<Result<CachedCompile>> {
+        let inputs: Vec<SfcInput> = inputs.into_iter().collect();
+
+        let placeholder = || Err(Error::new("compile_all: input was never scheduled"));
+        let results = Mutex::new(inputs.iter().map(|_| placeholder()).collect::<Vec<_>>());
+        let worker_count = self.max.min(inputs.len().max(1));
+        let queue = Mutex::new(inputs.into_iter().enumerate().collect::<Vec<_>>());
+
+        thread::scope(|scope| {
+            for _ in 0..worker_count {
+                scope.spawn(|| loop {
+                    let next = queue.lock().expect("compile_all queue poisoned").pop();
+                    let Some((index, input)) = next else {
+                        break;
+                    };
+                    let result = self.compile_sfc(input);
+                    results.lock().expect("compile_all results poisoned")[index] = result;
+                });
+            }
+        });
+
+        results.into_inner().expect("compile_all results poisoned")
+    }
+
+    /// Parses an SFC on a worker thread and returns its parse diagnostics.
+    pub fn parse(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+    ) -> Result<Vec<Diagnostic>> {
+        self.dispatch(|reply| Job::Parse {
+            source: source.into(),
+            filename: filename.into(),
+            reply,
+        })
+    }
+
+    /// Compiles the script block(s) of an SFC on a worker thread.
+    pub fn compile_script(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+        id: impl Into<String>,
+        is_prod: bool,
+    ) -> Result<CompiledOutput> {
+        self.dispatch(|reply| Job::CompileScript {
+            source: source.into(),
+            filename: filename.into(),
+            id: id.into(),
+            is_prod,
+            reply,
+        })
+    }
+
+    /// Compiles a template block on a worker thread.
+    pub fn compile_template(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+        id: impl Into<String>,
+        scoped: bool,
+    ) -> Result<CompiledOutput> {
+        self.dispatch(|reply| Job::CompileTemplate {
+            source: source.into(),
+            filename: filename.into(),
+            id: id.into(),
+            scoped,
+            reply,
+        })
+    }
+
+    /// Compiles a style block on a worker thread.
+    pub fn compile_style(
+        &self,
+        source: impl Into<String>,
+        filename: impl Into<String>,
+        id: impl Into<String>,
+        scoped: bool,
+    ) -> Result<CompiledOutput> {
+        self.dispatch(|reply| Job::CompileStyle {
+            source: source.into(),
+            filename: filename.into(),
+            id: id.into(),
+            scoped,
+            reply,
+        })
+    }
+
+    fn dispatch<T>(&self, to_job: impl FnOnce(Sender<Result<T>>) -> Job) -> Result<T> {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        self.dispatch_job(to_job(reply_tx))?;
+        reply_rx
+            .recv()
+            .map_err(|_| Error::new("compiler pool worker terminated before replying"))?
+    }
+
+    fn dispatch_job(&self, job: Job) -> Result<()> {
+        self.grow_if_needed();
+        self.sender
+            .send(job)
+            .map_err(|_| Error::new("compiler pool has no running workers"))
+    }
+
+    /// Spawns one more worker if the pool hasn't yet reached `max`.
+    ///
+    /// Called on every dispatch rather than only when the queue is
+    /// backlogged: that would need a way to observe queue depth, which
+    /// `mpsc::Receiver` doesn't expose. Spawning up to `max` workers during
+    /// the pool's first burst of concurrent demand and reusing them after
+    /// is a close approximation and keeps worker creation - the expensive
+    /// part - off the common, already-warm path.
+    fn grow_if_needed(&self) {
+        let mut spawned = self.spawned.lock().expect("compiler pool spawn count poisoned");
+        if *spawned >= self.max {
+            return;
+        }
+        *spawned += 1;
+
+        let receiver = Arc::clone(&self.receiver);
+        let handle = thread::spawn(move || worker_loop(receiver));
+        self.workers
+            .lock()
+            .expect("compiler pool workers poisoned")
+            .push(handle);
+    }
+}
+
+impl Drop for CompilerPool {
+    fn drop(&mut self) {
+        // `sender` is dropped implicitly after this runs, closing the
+        // channel so every worker's `recv()` returns `Err` and its loop
+        // exits; join them so the pool never outlives its own threads.
+        let workers = self.workers.get_mut().expect("compiler pool workers poisoned");
+        for worker in workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker_loop(receiver: Arc<Mutex<Receiver<Job>>>) {
+    let Ok(compiler) = Compiler::new() else {
+        return;
+    };
+
+    loop {
+        let job = receiver.lock().expect("compiler pool receiver poisoned").recv();
+        let Ok(job) = job else { break };
+        run_job(&compiler, job);
+        compiler.reset();
+    }
+}
+
+fn run_job(compiler: &Compiler, job: Job) {
+    match job {
+        Job::Parse {
+            source,
+            filename,
+            reply,
+        } => {
+            let result = compiler
+                .parse(&source, &filename)
+                .map(|parsed| parsed.diagnostics());
+            let _ = reply.send(result);
+        }
+        Job::CompileScript {
+            source,
+            filename,
+            id,
+            is_prod,
+            reply,
+        } => {
+            let _ = reply.send(compile_script(compiler, &source, &filename, &id, is_prod));
+        }
+        Job::CompileTemplate {
+            source,
+            filename,
+            id,
+            scoped,
+            reply,
+        } => {
+            let result = compiler
+                .compile_template(&source, &filename, &id, scoped, None)
+                .map(|output| CompiledOutput {
+                    code: output.code().to_string(),
+                    source_map: output.source_map().map(str::to_string),
+                    diagnostics: output.diagnostics(),
+                });
+            let _ = reply.send(result);
+        }
+        Job::CompileStyle {
+            source,
+            filename,
+            id,
+            scoped,
+            reply,
+        } => {
+            let result = compiler
+                .compile_style(&source, &filename, &id, scoped)
+                .map(|output| CompiledOutput {
+                    code: output.code().to_string(),
+                    source_map: output.source_map().map(str::to_string),
+                    diagnostics: output.diagnostics(),
+                });
+            let _ = reply.send(result);
+        }
+        Job::CompileSfc { input, reply } => {
+            let result = compiler.compile_cached(
+                &input.source,
+                &input.filename,
+                &input.id,
+                input.is_prod,
+                input.scoped,
+            );
+            let _ = reply.send(result);
+        }
+        Job::Scope(task) => task(compiler),
+    }
+}
+
+fn compile_script(
+    compiler: &Compiler,
+    source: &str,
+    filename: &str,
+    id: &str,
+    is_prod: bool,
+) -> Result<CompiledOutput> {
+    let parsed = compiler.parse(source, filename)?;
+    let descriptor = parsed
+        .descriptor()
+        .ok_or_else(|| Error::new("parse produced no descriptor"))?;
+    let output = descriptor.compile_script(id, is_prod)?;
+    Ok(CompiledOutput {
+        code: output.content().to_string(),
+        source_map: output.source_map().map(str::to_string),
+        diagnostics: output.diagnostics(),
+    })
+}
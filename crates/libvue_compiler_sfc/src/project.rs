@@ -0,0 +1,225 @@
+//! Parallel multi-file project compilation.
+//!
+//! `Compiler` is deliberately single-threaded: each instance owns one Hermes
+//! runtime, and parallel compilation means creating multiple instances. This
+//! module builds the "multiple instances" story a build tool actually wants,
+//! modeled on rustdoc's rendering architecture: one large immutable,
+//! `Sync`-shareable [`ProjectConfig`] is crawled/built once up front, and a
+//! lightweight per-thread [`Context`] is created for each worker and owns
+//! that worker's `Compiler` for the duration of the call. Workers pull files
+//! off a shared queue and compile them independently; the only
+//! synchronization is at the collection boundary, when a finished
+//! [`CompiledComponent`] is written into the shared result map.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::compiler::Compiler;
+use crate::types::{Error, Result};
+
+/// Read-only compile configuration shared across every worker thread.
+///
+/// Built once up front and wrapped in an `Arc` so each worker clones a cheap
+/// reference to it rather than copying it onto every thread.
+pub struct ProjectConfig {
+    /// Whether compiled templates should be treated as scoped.
+    pub scoped: bool,
+    /// Whether script compilation should target production output.
+    pub is_prod: bool,
+    /// Derives a scope ID (e.g. `data-v-abc123`) from a file's path.
+    pub scope_id: fn(&Path) -> String,
+    /// Resolves a file on disk to the module path used to key its compiled
+    /// output, mirroring how a bundler maps a file to its module id.
+    pub resolve_module: fn(&Path) -> PathBuf,
+}
+
+impl Default for ProjectConfig {
+    fn default() -> Self {
+        Self {
+            scoped: true,
+            is_prod: false,
+            scope_id: default_scope_id,
+            resolve_module: |path| path.to_path_buf(),
+        }
+    }
+}
+
+fn default_scope_id(path: &Path) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    format!("data-v-{:x}", hasher.finish() & 0xffff_ffff)
+}
+
+/// The compiled output of a single `.vue` file.
+#[derive(Debug, Clone, Default)]
+pub struct CompiledComponent {
+    /// Compiled `<script>`/`<script setup>` content, if present.
+    pub script: Option<String>,
+    /// Compiled render function code, if a `<template>` block was present.
+    pub template: Option<String>,
+    /// Compiled CSS for each `<style>` block, in document order.
+    pub styles: Vec<String>,
+}
+
+/// A directory of `.vue` files compiled together across a thread pool.
+pub struct Project {
+    config: Arc<ProjectConfig>,
+    files: Vec<PathBuf>,
+}
+
+impl Project {
+    /// Crawls `root` for `.vue` files and prepares a project over them.
+    pub fn new(root: impl AsRef<Path>, config: ProjectConfig) -> Result<Self> {
+        let mut files = Vec::new();
+        collect_vue_files(root.as_ref(), &mut files)?;
+        Ok(Self {
+            config: Arc::new(config),
+            files,
+        })
+    }
+
+    /// Compiles every `.vue` file, saturating all available cores.
+    pub fn compile_all(&self) -> Result<HashMap<PathBuf, CompiledComponent>> {
+        self.compile_all_with(worker_count())
+    }
+
+    /// Like [`Project::compile_all`], but with an explicit worker count.
+    pub fn compile_all_with(
+        &self,
+        worker_count: usize,
+    ) -> Result<HashMap<PathBuf, CompiledComponent>> {
+        let worker_count = worker_count.max(1).min(self.files.len().max(1));
+        let queue = Mutex::new(self.files.clone());
+        let results = Mutex::new(HashMap::with_capacity(self.files.len()));
+
+        thread::scope(|scope| -> Result<()> {
+            let mut handles = Vec::with_capacity(worker_count);
+            for _ in 0..worker_count {
+                let queue = &queue;
+                let results = &results;
+                let config = Arc::clone(&self.config);
+                handles.push(scope.spawn(move || -> Result<()> {
+                    let mut ctx = Context::new(config)?;
+                    loop {
+                        let next = queue.lock().unwrap().pop();
+                        let Some(path) = next else { break };
+                        let component = ctx.compile_file(&path)?;
+                        let key = (ctx.config.resolve_module)(&path);
+                        results.lock().unwrap().insert(key, component);
+                    }
+                    Ok(())
+                }));
+            }
+            for handle in handles {
+                handle
+                    .join()
+                    .map_err(|_| Error::new("worker thread panicked"))??;
+            }
+            Ok(())
+        })?;
+
+        Ok(results.into_inner().unwrap())
+    }
+}
+
+/// Per-thread compilation context.
+///
+/// Created fresh for each worker and owns that worker's `Compiler` (and
+/// therefore its own Hermes runtime), preserving the single-runtime-per-thread
+/// invariant `Compiler` requires.
+struct Context {
+    compiler: Compiler,
+    config: Arc<ProjectConfig>,
+}
+
+impl Context {
+    fn new(config: Arc<ProjectConfig>) -> Result<Self> {
+        Ok(Self {
+            compiler: Compiler::new()?,
+            config,
+        })
+    }
+
+    fn compile_file(&mut self, path: &Path) -> Result<CompiledComponent> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("failed to read {}: {e}", path.display())))?;
+        let filename = path.to_string_lossy();
+        let scope_id = (self.config.scope_id)(path);
+
+        let parsed = self.compiler.parse(&source, &filename)?;
+        let Some(descriptor) = parsed.descriptor() else {
+            return Ok(CompiledComponent::default());
+        };
+
+        let script = if descriptor.has_script() || descriptor.has_script_setup() {
+            Some(
+                descriptor
+                    .compile_script(&scope_id, self.config.is_prod)?
+                    .content()
+                    .to_string(),
+            )
+        } else {
+            None
+        };
+
+        let template = match descriptor.template() {
+            Some(template) => Some(
+                self.compiler
+                    .compile_template(
+                        template.content(),
+                        &filename,
+                        &scope_id,
+                        self.config.scoped,
+                        None,
+                    )?
+                    .code()
+                    .to_string(),
+            ),
+            None => None,
+        };
+
+        let styles = descriptor
+            .styles()
+            .map(|style| {
+                self.compiler
+                    .compile_style(style.content(), &filename, &scope_id, style.is_scoped())
+                    .map(|out| out.code().to_string())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(CompiledComponent {
+            script,
+            template,
+            styles,
+        })
+    }
+}
+
+fn collect_vue_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| Error::new(format!("failed to read directory {}: {e}", dir.display())))?;
+
+    for entry in entries {
+        let entry =
+            entry.map_err(|e| Error::new(format!("failed to read directory entry: {e}")))?;
+        let path = entry.path();
+        if path.is_dir() {
+            collect_vue_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "vue") {
+            out.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn worker_count() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
@@ -0,0 +1,608 @@
+//! Source Map V3 composition.
+//!
+//! [`merge_source_maps`] composes a chain of maps end to end: `chain[0]` maps
+//! the final generated output back to the coordinate space `chain[1]`
+//! describes, `chain[1]` maps that space back to `chain[2]`'s, and so on,
+//! down to a map that points at the original `.vue` source. This lets a
+//! consumer who concatenates, say, compiled script and template code and
+//! then needs one map pointing straight at the source file fold the two
+//! intermediate maps into a single one.
+
+use std::collections::HashMap;
+
+use crate::types::{Error, Result};
+
+const BASE64_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A decoded mapping segment, in the coordinate system of the map it came
+/// from. `source_idx`/`orig_line`/`orig_col`/`name_idx` are `None` for
+/// generated-only segments (no source position attached).
+#[derive(Clone, Copy)]
+struct Segment {
+    gen_col: i64,
+    source_idx: Option<i64>,
+    orig_line: Option<i64>,
+    orig_col: Option<i64>,
+    name_idx: Option<i64>,
+}
+
+/// A typed Source Map V3 document: the original-position mappings a
+/// compiled template/script/style's generated code carries back to its
+/// `.vue` source, for downstream bundlers and devtools to consume without
+/// having to parse the raw JSON themselves.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    /// Always `3`; the only version this crate produces or parses.
+    pub version: u8,
+    /// Original source files referenced by `mappings`, by index.
+    pub sources: Vec<String>,
+    /// The content of each entry in `sources`, parallel to it, if embedded.
+    pub sources_content: Vec<Option<String>>,
+    /// Original identifier names referenced by `mappings`, by index.
+    pub names: Vec<String>,
+    /// The Base64-VLQ-encoded mapping segments; see the module docs.
+    pub mappings: String,
+}
+
+impl Default for SourceMap {
+    fn default() -> Self {
+        Self {
+            version: 3,
+            sources: Vec::new(),
+            sources_content: Vec::new(),
+            names: Vec::new(),
+            mappings: String::new(),
+        }
+    }
+}
+
+impl SourceMap {
+    /// Parses a Source Map V3 JSON document.
+    pub fn parse(json: &str) -> Result<Self> {
+        let value = JsonParser::new(json).parse()?;
+        let Json::Object(fields) = value else {
+            return Err(Error::new("source map must be a JSON object"));
+        };
+
+        let mut map = SourceMap::default();
+        for (key, value) in fields {
+            match key.as_str() {
+                "sources" => map.sources = json_string_array(value)?,
+                "sourcesContent" => map.sources_content = json_optional_string_array(value)?,
+                "names" => map.names = json_string_array(value)?,
+                "mappings" => map.mappings = json_string(value)?,
+                _ => {}
+            }
+        }
+        Ok(map)
+    }
+
+    /// Serializes back to a Source Map V3 JSON document.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"version\":3,\"sources\":[");
+        push_string_array(&mut out, self.sources.iter().map(String::as_str));
+        out.push(']');
+
+        if self.sources_content.iter().any(Option::is_some) {
+            out.push_str(",\"sourcesContent\":[");
+            for (i, content) in self.sources_content.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                match content {
+                    Some(s) => {
+                        out.push('"');
+                        escape_json_into(s, &mut out);
+                        out.push('"');
+                    }
+                    None => out.push_str("null"),
+                }
+            }
+            out.push(']');
+        }
+
+        out.push_str(",\"names\":[");
+        push_string_array(&mut out, self.names.iter().map(String::as_str));
+        out.push_str("],\"mappings\":\"");
+        escape_json_into(&self.mappings, &mut out);
+        out.push_str("\"}");
+        out
+    }
+}
+
+/// Composes a chain of generated→source maps into one map that points
+/// straight from `chain[0]`'s generated output at `chain[chain.len() - 1]`'s
+/// original source.
+///
+/// Returns the composed map serialized as a Source Map V3 JSON string.
+pub fn merge_source_maps(chain: &[&str]) -> Result<String> {
+    let mut maps = chain
+        .iter()
+        .map(|json| SourceMap::parse(json))
+        .collect::<Result<Vec<_>>>()?;
+
+    if maps.is_empty() {
+        return Err(Error::new("merge_source_maps requires at least one map"));
+    }
+
+    let mut result = maps.remove(0);
+    for next in maps {
+        result = compose_two(&result, &next);
+    }
+
+    Ok(result.to_json())
+}
+
+/// Composes map `b` (final→intermediate) over map `a` (intermediate→original),
+/// producing a map from `b`'s generated coordinates straight to `a`'s
+/// original coordinates.
+fn compose_two(b: &SourceMap, a: &SourceMap) -> SourceMap {
+    let b_lines = decode_mappings(&b.mappings);
+    let a_lines = decode_mappings(&a.mappings);
+
+    let mut used_sources = Vec::new();
+    let mut used_names = Vec::new();
+    let mut source_index_map: HashMap<i64, usize> = HashMap::new();
+    let mut name_index_map: HashMap<i64, usize> = HashMap::new();
+
+    let out_lines: Vec<Vec<Segment>> = b_lines
+        .iter()
+        .map(|segments| {
+            segments
+                .iter()
+                .filter_map(|seg| {
+                    let inter_line = seg.orig_line?;
+                    let inter_col = seg.orig_col?;
+                    let a_segments = a_lines.get(usize::try_from(inter_line).ok()?)?;
+
+                    // Binary search for the A-segment with the greatest
+                    // generated column <= inter_col.
+                    let idx = a_segments.partition_point(|s| s.gen_col <= inter_col);
+                    if idx == 0 {
+                        return None;
+                    }
+                    let a_seg = a_segments[idx - 1];
+                    let a_source_idx = a_seg.source_idx?;
+                    let a_orig_line = a_seg.orig_line?;
+                    let a_orig_col = a_seg.orig_col?;
+
+                    let new_source_idx = *source_index_map.entry(a_source_idx).or_insert_with(|| {
+                        used_sources.push(
+                            a.sources
+                                .get(a_source_idx as usize)
+                                .cloned()
+                                .unwrap_or_default(),
+                        );
+                        used_sources.len() - 1
+                    }) as i64;
+
+                    let new_name_idx = a_seg.name_idx.map(|ni| {
+                        *name_index_map.entry(ni).or_insert_with(|| {
+                            used_names.push(a.names.get(ni as usize).cloned().unwrap_or_default());
+                            used_names.len() - 1
+                        }) as i64
+                    });
+
+                    Some(Segment {
+                        gen_col: seg.gen_col,
+                        source_idx: Some(new_source_idx),
+                        orig_line: Some(a_orig_line),
+                        orig_col: Some(a_orig_col),
+                        name_idx: new_name_idx,
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    SourceMap {
+        version: 3,
+        sources: used_sources,
+        sources_content: Vec::new(),
+        names: used_names,
+        mappings: encode_mappings(&out_lines),
+    }
+}
+
+/// Decodes a `mappings` string into per-generated-line segments, applying
+/// the running-total deltas the Source Map V3 spec defines: `gen_col` resets
+/// to absolute at the start of every line, while `source_idx`/`orig_line`/
+/// `orig_col`/`name_idx` accumulate across the whole document.
+fn decode_mappings(mappings: &str) -> Vec<Vec<Segment>> {
+    let mut source_idx = 0i64;
+    let mut orig_line = 0i64;
+    let mut orig_col = 0i64;
+    let mut name_idx = 0i64;
+
+    mappings
+        .split(';')
+        .map(|line| {
+            let mut gen_col = 0i64;
+            line.split(',')
+                .filter(|s| !s.is_empty())
+                .map(|segment| {
+                    let nums = decode_vlq_segment(segment);
+                    gen_col += nums.first().copied().unwrap_or(0);
+
+                    let mut seg = Segment {
+                        gen_col,
+                        source_idx: None,
+                        orig_line: None,
+                        orig_col: None,
+                        name_idx: None,
+                    };
+
+                    if nums.len() >= 4 {
+                        source_idx += nums[1];
+                        orig_line += nums[2];
+                        orig_col += nums[3];
+                        seg.source_idx = Some(source_idx);
+                        seg.orig_line = Some(orig_line);
+                        seg.orig_col = Some(orig_col);
+
+                        if nums.len() >= 5 {
+                            name_idx += nums[4];
+                            seg.name_idx = Some(name_idx);
+                        }
+                    }
+
+                    seg
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Re-encodes decoded lines back into a `mappings` string, reversing the
+/// same running-total scheme [`decode_mappings`] applies.
+fn encode_mappings(lines: &[Vec<Segment>]) -> String {
+    let mut out = String::new();
+    let mut prev_source_idx = 0i64;
+    let mut prev_orig_line = 0i64;
+    let mut prev_orig_col = 0i64;
+    let mut prev_name_idx = 0i64;
+
+    for (i, segments) in lines.iter().enumerate() {
+        if i > 0 {
+            out.push(';');
+        }
+        let mut prev_gen_col = 0i64;
+        for (j, seg) in segments.iter().enumerate() {
+            if j > 0 {
+                out.push(',');
+            }
+            encode_vlq_value(seg.gen_col - prev_gen_col, &mut out);
+            prev_gen_col = seg.gen_col;
+
+            if let (Some(si), Some(ol), Some(oc)) = (seg.source_idx, seg.orig_line, seg.orig_col) {
+                encode_vlq_value(si - prev_source_idx, &mut out);
+                encode_vlq_value(ol - prev_orig_line, &mut out);
+                encode_vlq_value(oc - prev_orig_col, &mut out);
+                prev_source_idx = si;
+                prev_orig_line = ol;
+                prev_orig_col = oc;
+
+                if let Some(ni) = seg.name_idx {
+                    encode_vlq_value(ni - prev_name_idx, &mut out);
+                    prev_name_idx = ni;
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Decodes one comma-separated segment (a run of Base64-VLQ integers) into
+/// its delta values.
+fn decode_vlq_segment(segment: &str) -> Vec<i64> {
+    let mut result = Vec::new();
+    let mut shift = 0u32;
+    let mut value = 0i64;
+
+    for b in segment.bytes() {
+        let digit = base64_decode(b) as i64;
+        let continuation = digit & 0x20;
+        value += (digit & 0x1f) << shift;
+
+        if continuation != 0 {
+            shift += 5;
+        } else {
+            let negate = value & 1 != 0;
+            let magnitude = value >> 1;
+            result.push(if negate { -magnitude } else { magnitude });
+            value = 0;
+            shift = 0;
+        }
+    }
+
+    result
+}
+
+/// Encodes a single signed integer as Base64-VLQ and appends it to `out`.
+fn encode_vlq_value(value: i64, out: &mut String) {
+    let mut vlq = if value < 0 { (-value << 1) | 1 } else { value << 1 };
+
+    loop {
+        let mut digit = vlq & 0x1f;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0x20;
+        }
+        out.push(BASE64_ALPHABET[digit as usize] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn base64_decode(b: u8) -> u8 {
+    match b {
+        b'A'..=b'Z' => b - b'A',
+        b'a'..=b'z' => b - b'a' + 26,
+        b'0'..=b'9' => b - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => 0,
+    }
+}
+
+// ---------------------------------------------------------------------
+// Minimal JSON parsing, just enough to read/write the handful of fields a
+// Source Map V3 document carries. Not a general-purpose JSON library.
+// ---------------------------------------------------------------------
+
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+struct JsonParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn parse(mut self) -> Result<Json> {
+        self.skip_ws();
+        self.parse_value()
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek_char(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn expect_lit(&mut self, lit: &str) -> Result<()> {
+        if self.rest().starts_with(lit) {
+            self.pos += lit.len();
+            Ok(())
+        } else {
+            Err(Error::new(format!("expected `{lit}` in JSON input")))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json> {
+        self.skip_ws();
+        match self.peek_char() {
+            Some('"') => self.parse_string().map(Json::String),
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('t') => {
+                self.expect_lit("true")?;
+                Ok(Json::Bool(true))
+            }
+            Some('f') => {
+                self.expect_lit("false")?;
+                Ok(Json::Bool(false))
+            }
+            Some('n') => {
+                self.expect_lit("null")?;
+                Ok(Json::Null)
+            }
+            Some(_) => self.parse_number(),
+            None => Err(Error::new("unexpected end of JSON input")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.bump(); // opening quote
+        let mut out = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => match self.bump() {
+                    Some('"') => out.push('"'),
+                    Some('\\') => out.push('\\'),
+                    Some('/') => out.push('/'),
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('r') => out.push('\r'),
+                    Some('u') => {
+                        let hex: String = (0..4).filter_map(|_| self.bump()).collect();
+                        let code = u32::from_str_radix(&hex, 16)
+                            .map_err(|_| Error::new("invalid \\u escape in JSON string"))?;
+                        if let Some(c) = char::from_u32(code) {
+                            out.push(c);
+                        }
+                    }
+                    _ => return Err(Error::new("invalid escape in JSON string")),
+                },
+                Some(c) => out.push(c),
+                None => return Err(Error::new("unterminated JSON string")),
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_number(&mut self) -> Result<Json> {
+        let start = self.pos;
+        if matches!(self.peek_char(), Some('-')) {
+            self.bump();
+        }
+        while matches!(self.peek_char(), Some(c) if c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+        {
+            self.bump();
+        }
+        self.input[start..self.pos]
+            .parse::<f64>()
+            .map(Json::Number)
+            .map_err(|_| Error::new("invalid JSON number"))
+    }
+
+    fn parse_array(&mut self) -> Result<Json> {
+        self.bump(); // [
+        let mut items = Vec::new();
+        self.skip_ws();
+        if self.peek_char() == Some(']') {
+            self.bump();
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_ws();
+            match self.bump() {
+                Some(',') => self.skip_ws(),
+                Some(']') => break,
+                _ => return Err(Error::new("expected `,` or `]` in JSON array")),
+            }
+        }
+        Ok(Json::Array(items))
+    }
+
+    fn parse_object(&mut self) -> Result<Json> {
+        self.bump(); // {
+        let mut fields = Vec::new();
+        self.skip_ws();
+        if self.peek_char() == Some('}') {
+            self.bump();
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_ws();
+            let key = self.parse_string()?;
+            self.skip_ws();
+            if self.bump() != Some(':') {
+                return Err(Error::new("expected `:` in JSON object"));
+            }
+            let value = self.parse_value()?;
+            fields.push((key, value));
+            self.skip_ws();
+            match self.bump() {
+                Some(',') => {}
+                Some('}') => break,
+                _ => return Err(Error::new("expected `,` or `}` in JSON object")),
+            }
+        }
+        Ok(Json::Object(fields))
+    }
+}
+
+fn json_string(value: Json) -> Result<String> {
+    match value {
+        Json::String(s) => Ok(s),
+        _ => Err(Error::new("expected a JSON string")),
+    }
+}
+
+fn json_string_array(value: Json) -> Result<Vec<String>> {
+    match value {
+        Json::Array(items) => items.into_iter().map(json_string).collect(),
+        _ => Err(Error::new("expected a JSON array")),
+    }
+}
+
+fn json_optional_string_array(value: Json) -> Result<Vec<Option<String>>> {
+    match value {
+        Json::Array(items) => Ok(items
+            .into_iter()
+            .map(|item| match item {
+                Json::String(s) => Some(s),
+                _ => None,
+            })
+            .collect()),
+        _ => Err(Error::new("expected a JSON array")),
+    }
+}
+
+fn push_string_array<'a>(out: &mut String, items: impl Iterator<Item = &'a str>) {
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push('"');
+        escape_json_into(item, out);
+        out.push('"');
+    }
+}
+
+fn escape_json_into(s: &str, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vlq_round_trips_through_decode_and_encode() {
+        for value in [0i64, 1, -1, 16, -16, 12345, -12345] {
+            let mut encoded = String::new();
+            encode_vlq_value(value, &mut encoded);
+            assert_eq!(decode_vlq_segment(&encoded), vec![value]);
+        }
+    }
+
+    #[test]
+    fn merge_source_maps_composes_two_levels() {
+        // `a`: one generated line, one segment mapping straight to line 0
+        // col 0 of "original.vue".
+        let a = r#"{"version":3,"sources":["original.vue"],"names":[],"mappings":"AAAA"}"#;
+        // `b`: one generated line, one segment mapping generated col 0 to
+        // line 0 col 0 of `a`'s coordinate space ("intermediate").
+        let b = r#"{"version":3,"sources":["intermediate"],"names":[],"mappings":"AAAA"}"#;
+
+        let merged = merge_source_maps(&[b, a]).unwrap();
+        assert!(merged.contains("\"original.vue\""));
+        assert!(!merged.contains("intermediate"));
+    }
+
+    #[test]
+    fn merge_source_maps_requires_at_least_one_map() {
+        assert!(merge_source_maps(&[]).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+//! Tests for [`crate::CompilerActor`]/[`crate::CompilerHandle`].
+
+use crate::CompilerActor;
+
+#[test]
+fn dropping_one_clone_leaves_the_others_able_to_complete_work() {
+    let handle = CompilerActor::spawn();
+    let other = handle.clone();
+
+    drop(handle);
+
+    let diagnostics = other
+        .parse("<template><div>{{ msg }}</div></template>", "Actor.vue")
+        .expect("parse should still succeed on the surviving handle");
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn shutdown_on_one_clone_does_not_stop_the_others() {
+    let handle = CompilerActor::spawn();
+    let other = handle.clone();
+
+    handle.shutdown();
+
+    let output = other
+        .compile_script(
+            "<script setup>\nconst x = 1\n</script>\n",
+            "Actor.vue",
+            "data-v-actortest",
+            false,
+        )
+        .expect("compile_script should still succeed on the surviving handle");
+    assert!(output.code.contains('x'));
+}
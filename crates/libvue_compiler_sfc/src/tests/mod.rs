@@ -0,0 +1,4 @@
+mod actor_tests;
+mod pool_tests;
+mod project_tests;
+mod snapshot_tests;
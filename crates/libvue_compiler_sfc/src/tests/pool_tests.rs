@@ -0,0 +1,50 @@
+//! Tests for [`crate::CompilerPool::compile_all`].
+
+use crate::{CompilerPool, SfcInput};
+
+fn input(id: &str, msg: &str) -> SfcInput {
+    SfcInput {
+        source: format!(
+            "<template><div>{{{{ msg }}}}</div></template>\n<script setup>\nconst msg = '{msg}'\n</script>\n"
+        ),
+        filename: format!("{id}.vue"),
+        id: format!("data-v-{id}"),
+        is_prod: false,
+        scoped: true,
+    }
+}
+
+#[test]
+fn compile_all_returns_results_in_input_order() {
+    let pool = CompilerPool::new(2);
+    let inputs = vec![input("a", "one"), input("b", "two"), input("c", "three")];
+
+    let results = pool.compile_all(inputs);
+
+    assert_eq!(results.len(), 3);
+    let scripts: Vec<String> = results
+        .into_iter()
+        .map(|r| r.unwrap().script.unwrap())
+        .collect();
+    assert!(scripts[0].contains("one"));
+    assert!(scripts[1].contains("two"));
+    assert!(scripts[2].contains("three"));
+}
+
+#[test]
+fn compile_all_handles_more_inputs_than_workers() {
+    let pool = CompilerPool::new(2);
+    let inputs: Vec<SfcInput> = (0..10).map(|i| input(&format!("f{i}"), "x")).collect();
+
+    let results = pool.compile_all(inputs);
+
+    assert_eq!(results.len(), 10);
+    assert!(results.iter().all(|r| r.is_ok()));
+}
+
+#[test]
+fn compile_all_on_empty_input_returns_empty() {
+    let pool = CompilerPool::new(4);
+    let results = pool.compile_all(Vec::new());
+    assert!(results.is_empty());
+}
@@ -0,0 +1,59 @@
+//! Tests for [`crate::Project`] multi-file compilation.
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{Project, ProjectConfig};
+
+/// Creates a fresh scratch directory under the system temp dir, unique to
+/// `name`, so parallel test runs don't collide on the same files.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("vuers-project-test-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn compile_all_compiles_every_vue_file_in_the_tree() {
+    let root = scratch_dir("compile-all");
+    fs::create_dir_all(root.join("components")).unwrap();
+    fs::write(
+        root.join("App.vue"),
+        "<template><div>{{ msg }}</div></template>\n<script setup>\nconst msg = 'hi'\n</script>\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("components/Child.vue"),
+        "<template><span>child</span></template>\n<style>span { color: red; }</style>\n",
+    )
+    .unwrap();
+
+    let project = Project::new(&root, ProjectConfig::default()).unwrap();
+    let results = project.compile_all().unwrap();
+
+    assert_eq!(results.len(), 2);
+    let app = &results[&root.join("App.vue")];
+    assert!(app.script.as_ref().unwrap().contains("hi"));
+    assert!(app.template.is_some());
+
+    let child = &results[&root.join("components/Child.vue")];
+    assert!(child.script.is_none());
+    assert_eq!(child.styles.len(), 1);
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn compile_all_with_caps_worker_count_to_file_count() {
+    let root = scratch_dir("worker-cap");
+    fs::write(root.join("Only.vue"), "<template><div /></template>\n").unwrap();
+
+    let project = Project::new(&root, ProjectConfig::default()).unwrap();
+    // More workers than files: must not panic or deadlock spinning up
+    // workers with nothing left on the queue.
+    let results = project.compile_all_with(8).unwrap();
+
+    assert_eq!(results.len(), 1);
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -8,7 +8,10 @@
 
 use std::collections::BTreeMap;
 
-use crate::{Compiler, AttrValue, ImportBinding, Position, SourceLocation};
+use crate::{
+    Compiler, AttrValue, BlockKind, DependencyKind, ImportBinding, Position, ScriptOptions,
+    SourceLocation, StyleOptions, TemplateOptions,
+};
 
 /// Serializable struct for Position (for deterministic output)
 #[derive(Debug)]
@@ -514,6 +517,155 @@ export default {}
     insta::assert_debug_snapshot!("attribute_formats", snapshot);
 }
 
+/// Test that script/template/style compilation surface a Source Map V3
+/// document when source maps are requested, and that it decodes back to a
+/// valid [`crate::SourceMap`].
+#[test]
+fn test_compiled_output_source_maps() {
+    let source = r#"<template>
+  <div>{{ count }}</div>
+</template>
+
+<script setup>
+const count = 1
+</script>
+
+<style scoped>
+.box { color: red; }
+</style>
+"#;
+
+    let compiler = Compiler::new().expect("Compiler should initialize");
+    let parsed = compiler.parse(source, "SourceMap.vue").expect("Parse should succeed");
+    let desc = parsed.descriptor().expect("descriptor should be present");
+
+    let script = desc
+        .compile_script_with(&ScriptOptions::new().scope_id("data-v-smtest").source_map(true))
+        .expect("script should compile");
+    let script_map = script.source_map().expect("script source map should be present");
+    let script_parsed_map = script
+        .parsed_source_map()
+        .expect("script source map should parse")
+        .expect("script source map should be present");
+
+    let template = compiler
+        .compile_template_with(
+            desc.template().unwrap().content(),
+            &TemplateOptions::new()
+                .filename("SourceMap.vue")
+                .scope_id("data-v-smtest")
+                .scoped(true)
+                .source_map(true),
+        )
+        .expect("template should compile");
+    let template_map = template.source_map().expect("template source map should be present");
+
+    let style = compiler
+        .compile_style_with(
+            ".box { color: red; }",
+            &StyleOptions::new()
+                .filename("SourceMap.vue")
+                .scope_id("data-v-smtest")
+                .scoped(true)
+                .source_map(true),
+        )
+        .expect("style should compile");
+    let style_map = style.source_map().expect("style source map should be present");
+
+    insta::assert_debug_snapshot!(
+        "compiled_output_source_maps",
+        (
+            script_parsed_map.version,
+            script_map.contains("\"version\":3"),
+            template_map.contains("\"version\":3"),
+            style_map.contains("\"version\":3"),
+        )
+    );
+}
+
+/// Test that a script compile error surfaces as a structured [`crate::Diagnostic`]
+/// with a source location, not just a bare message.
+#[test]
+fn test_script_diagnostics_carry_source_location() {
+    // Invalid JS: an unterminated statement the Vue compiler's script
+    // transform should reject during parsing.
+    let source = r#"<script setup>
+const x = ;
+</script>
+"#;
+
+    let compiler = Compiler::new().expect("Compiler should initialize");
+    let parsed = compiler.parse(source, "Diagnostics.vue").expect("Parse should succeed");
+    let desc = parsed.descriptor().expect("descriptor should be present");
+
+    let result = desc.compile_script("data-v-diagtest", false);
+    let diagnostics = match &result {
+        Ok(output) => output.diagnostics(),
+        Err(_) => Vec::new(),
+    };
+
+    insta::assert_debug_snapshot!(
+        "script_diagnostics_carry_source_location",
+        (
+            result.is_err() || !diagnostics.is_empty(),
+            diagnostics.iter().any(|d| d.loc.is_some()),
+        )
+    );
+}
+
+/// Test that [`crate::StyleOutput::css_var_map`] maps every `v-bind()`
+/// expression in a scoped style to its generated CSS custom property.
+#[test]
+fn test_style_css_var_map() {
+    let compiler = Compiler::new().expect("Compiler should initialize");
+    let style = compiler
+        .compile_style(
+            ".box { color: v-bind(primaryColor); }",
+            "CssVar.vue",
+            "data-v-cssvar",
+            true,
+        )
+        .expect("style should compile");
+
+    let mut vars: Vec<(String, String)> = style.css_var_map().into_iter().collect();
+    vars.sort();
+
+    insta::assert_debug_snapshot!("style_css_var_map", (style.css_var_map_count(), vars));
+}
+
+/// Test that [`crate::Descriptor::module_graph`] collects every static
+/// import, dynamic import, and `src=` reference across both scripts and
+/// styles into one de-duplicated dependency list.
+#[test]
+fn test_descriptor_module_graph() {
+    let source = r#"<script>
+import { defineComponent } from 'vue'
+export default defineComponent({})
+</script>
+
+<script setup>
+import { ref } from 'vue'
+const lazy = () => import('./Lazy.vue')
+const count = ref(0)
+</script>
+
+<style src="./external.css"></style>
+"#;
+
+    let compiler = Compiler::new().expect("Compiler should initialize");
+    let parsed = compiler.parse(source, "ModuleGraph.vue").expect("Parse should succeed");
+    let desc = parsed.descriptor().expect("descriptor should be present");
+
+    let mut deps: Vec<(String, DependencyKind, BlockKind)> = desc
+        .module_graph()
+        .into_iter()
+        .map(|dep| (dep.specifier, dep.kind, dep.block))
+        .collect();
+    deps.sort_by(|a, b| format!("{a:?}").cmp(&format!("{b:?}")));
+
+    insta::assert_debug_snapshot!("descriptor_module_graph", deps);
+}
+
 /// Helper function to build a complete ParseOutputSnapshot
 fn build_parse_output_snapshot(result: &crate::ParseOutput) -> ParseOutputSnapshot {
     ParseOutputSnapshot {
@@ -0,0 +1,84 @@
+//! Builder-style options for [`crate::Compiler::compile_with`].
+
+use crate::ImportMap;
+
+/// Fluent, whole-SFC builder for [`crate::Compiler::compile_with`], composing
+/// the per-block [`crate::ScriptOptions`]/[`crate::TemplateOptions`]/
+/// [`crate::StyleOptions`] knobs that matter across a full client-vs-SSR
+/// build rather than one block at a time.
+///
+/// Preprocessing `lang="scss"`/`lang="pug"` blocks happens upstream of this
+/// crate (on already-extracted block source), so there is no
+/// `preprocess_options` knob here: there is nothing in the FFI surface for
+/// it to plumb into.
+#[derive(Debug, Clone)]
+pub struct CompileOptions {
+    pub(crate) scope_id: String,
+    pub(crate) is_dev: bool,
+    pub(crate) ssr: bool,
+    pub(crate) scoped: bool,
+    pub(crate) source_map: bool,
+    pub(crate) import_map: Option<ImportMap>,
+}
+
+impl Default for CompileOptions {
+    fn default() -> Self {
+        Self {
+            scope_id: String::new(),
+            is_dev: true,
+            ssr: false,
+            scoped: false,
+            source_map: false,
+            import_map: None,
+        }
+    }
+}
+
+impl CompileOptions {
+    /// Starts a new builder with default (dev, client-target) options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the scope ID for scoped CSS (e.g. `"data-v-abc123"`).
+    pub fn scope_id(mut self, scope_id: impl Into<String>) -> Self {
+        self.scope_id = scope_id.into();
+        self
+    }
+
+    /// Sets whether to keep dev-only checks (the inverse of `is_prod`).
+    pub fn is_dev(mut self, is_dev: bool) -> Self {
+        self.is_dev = is_dev;
+        self
+    }
+
+    /// Sets whether to compile the template for server-side rendering.
+    pub fn ssr(mut self, ssr: bool) -> Self {
+        self.ssr = ssr;
+        self
+    }
+
+    /// Sets whether to add scoped attribute selectors.
+    pub fn scoped(mut self, scoped: bool) -> Self {
+        self.scoped = scoped;
+        self
+    }
+
+    /// Sets whether to generate source maps for the compiled output.
+    pub fn source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    /// Sets an [`ImportMap`] to resolve bare specifiers (e.g. `"vue"`) in
+    /// the compiled script to browser-native ESM or CDN URLs before
+    /// [`crate::Compiler::compile_with`] emits it.
+    pub fn import_map(mut self, import_map: ImportMap) -> Self {
+        self.import_map = Some(import_map);
+        self
+    }
+
+    pub(crate) fn is_prod(&self) -> bool {
+        !self.is_dev
+    }
+}
@@ -1,11 +1,14 @@
 //! SFC Descriptor type.
 
+use std::collections::HashSet;
 use std::os::raw::c_char;
 
 use super::custom_block::CustomBlock;
 use super::error::{Error, Result};
 use super::handle::Handle;
+use super::module_graph::{BlockKind, DependencyKind, ModuleDependency};
 use super::script_block::ScriptBlock;
+use super::script_options::ScriptOptions;
 use super::script_output::ScriptOutput;
 use super::style_block::StyleBlock;
 use super::template_block::TemplateBlock;
@@ -147,17 +150,123 @@ impl<'c> Descriptor<'c> {
 
     /// Compile the script blocks from this descriptor.
     pub fn compile_script(&self, id: &str, is_prod: bool) -> Result<ScriptOutput<'c>> {
+        self.compile_script_with(&ScriptOptions::new().scope_id(id).prod(is_prod))
+    }
+
+    /// Compile the script blocks from this descriptor using a
+    /// [`ScriptOptions`] builder, for the `source_map`/`inline_template`/
+    /// `ts`/`hmr` flags [`Self::compile_script`]'s fixed positional
+    /// arguments have no room for.
+    pub fn compile_script_with(&self, options: &ScriptOptions) -> Result<ScriptOutput<'c>> {
         let handle = unsafe {
-            ffi::vue_compile_script(
+            ffi::vue_compile_script_with_options(
                 *self.0.runtime(),
                 self.0.raw(),
-                id.as_ptr() as *const c_char,
-                id.len(),
-                is_prod,
+                options.scope_id.as_ptr() as *const c_char,
+                options.scope_id.len(),
+                options.prod,
+                options.source_map,
+                options.inline_template,
+                options.ts,
+                options.hmr,
             )
         };
         Handle::new(handle, self.0.runtime())
             .map(ScriptOutput::from_handle)
             .ok_or_else(|| Error::new("compile_script returned invalid handle"))
     }
+
+    /// Walks every block - both scripts, each style, and the template - and
+    /// returns the de-duplicated set of modules this SFC depends on: static
+    /// imports and re-exports, dynamic `import()` calls, and `src=`
+    /// references to external files.
+    ///
+    /// Template asset references (e.g. an `<img src="...">` inside the
+    /// compiled render function) aren't included: resolving those requires
+    /// walking the compiled template AST, which this crate doesn't expose.
+    pub fn module_graph(&self) -> Vec<ModuleDependency> {
+        let mut deps = Vec::new();
+        let mut seen = HashSet::new();
+
+        if let Some(script) = self.script() {
+            collect_script_deps(&script, BlockKind::Script, &mut deps, &mut seen);
+        }
+        if let Some(script_setup) = self.script_setup() {
+            collect_script_deps(&script_setup, BlockKind::ScriptSetup, &mut deps, &mut seen);
+        }
+        if let Some(template) = self.template() {
+            if let Some(src) = template.src() {
+                push(
+                    &mut deps,
+                    &mut seen,
+                    src.to_string(),
+                    DependencyKind::Src,
+                    BlockKind::Template,
+                    template.loc(),
+                );
+            }
+        }
+        for style in self.styles() {
+            if let Some(src) = style.src() {
+                push(
+                    &mut deps,
+                    &mut seen,
+                    src.to_string(),
+                    DependencyKind::Src,
+                    BlockKind::Style,
+                    style.loc(),
+                );
+            }
+        }
+
+        deps
+    }
+}
+
+/// Adds every import this script block references to `deps`, skipping
+/// specifiers already recorded for the same block. Named imports/re-exports
+/// surfaced by [`ScriptBlock::imports`] are [`DependencyKind::Static`];
+/// anything [`ScriptBlock::deps`] reports beyond those is classified
+/// [`DependencyKind::Dynamic`] - the current FFI surface doesn't carry a
+/// static/dynamic flag or a per-import span, so dynamic entries fall back to
+/// the block's own [`SourceLocation`].
+fn collect_script_deps(
+    script: &ScriptBlock<'_>,
+    block: BlockKind,
+    deps: &mut Vec<ModuleDependency>,
+    seen: &mut HashSet<(String, DependencyKind, BlockKind)>,
+) {
+    let loc = script.loc();
+    let mut static_sources = HashSet::new();
+
+    for binding in script.imports().into_values() {
+        static_sources.insert(binding.source.clone());
+        push(deps, seen, binding.source, DependencyKind::Static, block, loc);
+    }
+
+    for dep in script.deps() {
+        if static_sources.contains(&dep) {
+            continue;
+        }
+        push(deps, seen, dep, DependencyKind::Dynamic, block, loc);
+    }
+}
+
+fn push(
+    deps: &mut Vec<ModuleDependency>,
+    seen: &mut HashSet<(String, DependencyKind, BlockKind)>,
+    specifier: String,
+    kind: DependencyKind,
+    block: BlockKind,
+    loc: super::source_location::SourceLocation,
+) {
+    let key = (specifier.clone(), kind, block);
+    if seen.insert(key) {
+        deps.push(ModuleDependency {
+            specifier,
+            kind,
+            block,
+            loc,
+        });
+    }
 }
@@ -0,0 +1,256 @@
+//! Structured compiler diagnostics.
+//!
+//! Modeled on the error-class approach Deno's core error module uses: a
+//! diagnostic is a message paired with an optional machine-readable code,
+//! a severity, and the source span it applies to, rather than a bare string.
+
+use std::os::raw::c_char;
+
+use super::source_location::{Position, SourceLocation};
+use crate::ffi::{HermesHandle, HermesRuntime};
+use crate::util::ptr_to_str;
+
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// A fatal compile error.
+    Error,
+    /// A non-fatal warning.
+    Warning,
+}
+
+/// A single parse/compile diagnostic.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// Human-readable diagnostic message.
+    pub message: String,
+    /// Machine-readable error code, if the compiler attached one.
+    pub code: Option<String>,
+    /// Whether this is an error or a warning.
+    pub severity: Severity,
+    /// Source span the diagnostic applies to, if the compiler reported one.
+    pub loc: Option<SourceLocation>,
+}
+
+/// The per-index FFI accessors needed to build a [`Diagnostic`] list for one
+/// result type (parse/template/script/style results all expose the same
+/// shape under their own function names).
+pub(crate) struct DiagnosticFfi {
+    pub message: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> *const c_char,
+    pub code: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> *const c_char,
+    pub is_warning: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> bool,
+    pub has_loc: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> bool,
+    pub loc_start_offset: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> usize,
+    pub loc_start_line: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> usize,
+    pub loc_start_column: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> usize,
+    pub loc_end_offset: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> usize,
+    pub loc_end_line: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> usize,
+    pub loc_end_column: unsafe extern "C" fn(HermesRuntime, HermesHandle, usize) -> usize,
+}
+
+impl Diagnostic {
+    /// Render this diagnostic as an annotated source snippet: a line-number
+    /// gutter followed by the offending line(s) with a caret/underline
+    /// beneath the span, in the style of `annotate-snippets` (the crate
+    /// rustc and cargo use for the same purpose). Multi-line spans are
+    /// truncated to their first and last line. Falls back to the bare
+    /// message if this diagnostic has no location.
+    pub fn render(&self, source: &str) -> String {
+        let Some(loc) = &self.loc else {
+            return self.message.clone();
+        };
+
+        let label = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let gutter_width = loc.end.line.max(loc.start.line).to_string().len();
+
+        let mut out = format!("{label}: {}\n", self.message);
+        render_gutter_line(&mut out, source, loc.start.line, gutter_width);
+        let start_line_len = line_len(source, loc.start.line) + 1;
+        out.push_str(&underline(
+            gutter_width,
+            loc.start.column,
+            if loc.end.line == loc.start.line {
+                // The compiler's reported column can run past the line's
+                // actual length if `source` has since changed underneath
+                // this diagnostic - clamp so the underline doesn't.
+                loc.end.column.min(start_line_len)
+            } else {
+                start_line_len
+            },
+        ));
+
+        if loc.end.line != loc.start.line {
+            out.push_str(&format!("{:width$} | ...\n", "", width = gutter_width));
+            render_gutter_line(&mut out, source, loc.end.line, gutter_width);
+            let end_line_len = line_len(source, loc.end.line) + 1;
+            out.push_str(&underline(gutter_width, 1, loc.end.column.min(end_line_len)));
+        }
+
+        out
+    }
+
+    /// Like [`Self::render`], but prefixed with a rustc-style
+    /// `filename:line:col` header identifying the span's start, the
+    /// convention rust's own region-error reporting uses to let an editor
+    /// jump straight to the offending code.
+    pub fn render_with_filename(&self, source: &str, filename: &str) -> String {
+        let Some(loc) = &self.loc else {
+            return format!("{filename}: {}", self.message);
+        };
+
+        format!(
+            "{filename}:{}:{}\n{}",
+            loc.start.line,
+            loc.start.column,
+            self.render(source)
+        )
+    }
+}
+
+/// Appends `"<n> | <line text>\n"` for the given 1-indexed line, if it exists.
+fn render_gutter_line(out: &mut String, source: &str, line_no: usize, gutter_width: usize) {
+    if let Some(text) = source.lines().nth(line_no.saturating_sub(1)) {
+        out.push_str(&format!("{line_no:>gutter_width$} | {text}\n"));
+    }
+}
+
+/// Length in characters of the given 1-indexed line, or 0 if out of range.
+fn line_len(source: &str, line_no: usize) -> usize {
+    source
+        .lines()
+        .nth(line_no.saturating_sub(1))
+        .map(|line| line.chars().count())
+        .unwrap_or(0)
+}
+
+/// Builds the blank gutter + caret underline row for a 1-indexed column span.
+fn underline(gutter_width: usize, start_column: usize, end_column: usize) -> String {
+    let start = start_column.saturating_sub(1);
+    let span_width = end_column.saturating_sub(start_column).max(1);
+    format!(
+        "{:gutter_width$} | {}{}\n",
+        "",
+        " ".repeat(start),
+        "^".repeat(span_width),
+    )
+}
+
+/// Builds the `Diagnostic` list for a result handle given its per-index FFI
+/// accessors.
+pub(crate) unsafe fn collect_diagnostics(
+    rt: HermesRuntime,
+    handle: HermesHandle,
+    count: usize,
+    accessors: &DiagnosticFfi,
+) -> Vec<Diagnostic> {
+    (0..count)
+        .map(|i| {
+            let message = ptr_to_str((accessors.message)(rt, handle, i)).to_string();
+
+            let code_str = ptr_to_str((accessors.code)(rt, handle, i));
+            let code = if code_str.is_empty() {
+                None
+            } else {
+                Some(code_str.to_string())
+            };
+
+            let severity = if (accessors.is_warning)(rt, handle, i) {
+                Severity::Warning
+            } else {
+                Severity::Error
+            };
+
+            let loc = if (accessors.has_loc)(rt, handle, i) {
+                Some(SourceLocation {
+                    start: Position {
+                        offset: (accessors.loc_start_offset)(rt, handle, i),
+                        line: (accessors.loc_start_line)(rt, handle, i),
+                        column: (accessors.loc_start_column)(rt, handle, i),
+                    },
+                    end: Position {
+                        offset: (accessors.loc_end_offset)(rt, handle, i),
+                        line: (accessors.loc_end_line)(rt, handle, i),
+                        column: (accessors.loc_end_column)(rt, handle, i),
+                    },
+                })
+            } else {
+                None
+            };
+
+            Diagnostic {
+                message,
+                code,
+                severity,
+                loc,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(message: &str, loc: Option<SourceLocation>) -> Diagnostic {
+        Diagnostic {
+            message: message.to_string(),
+            code: None,
+            severity: Severity::Error,
+            loc,
+        }
+    }
+
+    fn loc(start_line: usize, start_col: usize, end_line: usize, end_col: usize) -> SourceLocation {
+        SourceLocation {
+            start: Position {
+                offset: 0,
+                line: start_line,
+                column: start_col,
+            },
+            end: Position {
+                offset: 0,
+                line: end_line,
+                column: end_col,
+            },
+        }
+    }
+
+    #[test]
+    fn render_falls_back_to_bare_message_without_a_location() {
+        let diag = diagnostic("something broke", None);
+        assert_eq!(diag.render("const x = 1;"), "something broke");
+    }
+
+    #[test]
+    fn render_underlines_a_single_line_span() {
+        let source = "const x = ;\n";
+        let diag = diagnostic("unexpected token", Some(loc(1, 11, 1, 12)));
+
+        let rendered = diag.render(source);
+        assert!(rendered.starts_with("error: unexpected token\n"));
+        assert!(rendered.contains("const x = ;"));
+        assert!(rendered.contains('^'));
+    }
+
+    #[test]
+    fn render_clamps_a_column_past_the_current_line_length() {
+        // The line is shorter than `end.column` claims - e.g. `source` was
+        // edited after the diagnostic was produced - so the underline must
+        // not run past the line's actual end.
+        let source = "x\n";
+        let diag = diagnostic("stale diagnostic", Some(loc(1, 1, 1, 100)));
+
+        let rendered = diag.render(source);
+        assert!(rendered.lines().last().unwrap().trim_end().ends_with('^'));
+    }
+
+    #[test]
+    fn render_with_filename_prefixes_a_rustc_style_header() {
+        let diag = diagnostic("bad thing", Some(loc(2, 3, 2, 4)));
+        let rendered = diag.render_with_filename("a\nbc\n", "App.vue");
+        assert!(rendered.starts_with("App.vue:2:3\n"));
+    }
+}
@@ -1,6 +1,6 @@
-//! Error types for the bindings API.
+//! Error types for SFC parsing and compilation.
 
-/// Error type for binding operations.
+/// Error type for compiler operations.
 #[derive(Debug)]
 pub struct Error(String);
 
@@ -24,5 +24,5 @@ impl std::fmt::Display for Error {
 
 impl std::error::Error for Error {}
 
-/// Result type alias for binding operations.
+/// Result type alias for compiler operations.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -4,30 +4,44 @@
 //! to JavaScript objects. Handles are automatically freed on drop.
 
 mod attr_value;
+mod compile_options;
 mod custom_block;
 mod descriptor;
+mod diagnostic;
 mod error;
 mod handle;
 mod import_binding;
+mod module_graph;
+mod parse_options;
 mod parse_output;
 mod script_block;
+mod script_options;
 mod script_output;
 mod source_location;
 mod style_block;
+mod style_options;
 mod style_output;
 mod template_block;
+mod template_options;
 mod template_output;
 
 pub use attr_value::AttrValue;
+pub use compile_options::CompileOptions;
 pub use custom_block::CustomBlock;
 pub use descriptor::Descriptor;
+pub use diagnostic::{Diagnostic, Severity};
 pub use error::{Error, Result};
 pub use import_binding::ImportBinding;
+pub use module_graph::{BlockKind, DependencyKind, ModuleDependency};
+pub use parse_options::ParseOptions;
 pub use parse_output::ParseOutput;
 pub use script_block::ScriptBlock;
+pub use script_options::ScriptOptions;
 pub use script_output::ScriptOutput;
 pub use source_location::{Position, SourceLocation};
 pub use style_block::StyleBlock;
-pub use style_output::StyleOutput;
+pub use style_options::StyleOptions;
+pub use style_output::{StyleOutput, StyleSnapshot};
 pub use template_block::TemplateBlock;
-pub use template_output::TemplateOutput;
+pub use template_options::{TemplateOptions, Whitespace};
+pub use template_output::{TemplateOutput, TemplateSnapshot};
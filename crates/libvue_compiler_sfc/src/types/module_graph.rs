@@ -0,0 +1,44 @@
+//! Whole-SFC module dependency graph.
+
+use super::source_location::SourceLocation;
+
+/// Which SFC block a [`ModuleDependency`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockKind {
+    /// The regular `<script>` block.
+    Script,
+    /// The `<script setup>` block.
+    ScriptSetup,
+    /// The `<template>` block.
+    Template,
+    /// A `<style>` block.
+    Style,
+}
+
+/// How a [`ModuleDependency`] was referenced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DependencyKind {
+    /// A static `import ... from '...'` or re-export.
+    Static,
+    /// A dynamic `import('...')`, or anything [`super::ScriptBlock::deps`]
+    /// reports that isn't already a static import binding - the current
+    /// FFI surface doesn't distinguish the two further.
+    Dynamic,
+    /// A block-level `src="..."` attribute pointing at an external file.
+    Src,
+}
+
+/// One dependency discovered by [`super::Descriptor::module_graph`].
+#[derive(Debug, Clone)]
+pub struct ModuleDependency {
+    /// The raw specifier or path as written in the source.
+    pub specifier: String,
+    /// How it was referenced.
+    pub kind: DependencyKind,
+    /// Which block it came from.
+    pub block: BlockKind,
+    /// Where in the SFC source the reference occurs. For [`DependencyKind::Dynamic`]
+    /// entries this is the enclosing block's location: the current FFI
+    /// surface reports script dependencies without a per-import span.
+    pub loc: SourceLocation,
+}
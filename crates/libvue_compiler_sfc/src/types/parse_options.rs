@@ -0,0 +1,54 @@
+//! Builder-style options for [`crate::Compiler::parse_with`].
+
+use super::template_options::Whitespace;
+
+/// Fluent builder for [`crate::Compiler::parse_with`], following the
+/// options-struct pattern [`super::TemplateOptions`] uses in place of a
+/// long, hard-to-extend positional argument list.
+///
+/// # Example
+///
+/// ```ignore
+/// let options = ParseOptions::new().whitespace(Whitespace::Preserve).pad(true);
+/// compiler.parse_with(source, "App.vue", &options)?;
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseOptions {
+    pub(crate) source_map: bool,
+    pub(crate) ignore_empty: bool,
+    pub(crate) whitespace: Whitespace,
+    pub(crate) pad: bool,
+}
+
+impl ParseOptions {
+    /// Starts a new builder with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to generate a source map for each parsed block.
+    pub fn source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    /// Sets whether to drop blocks that contain only whitespace.
+    pub fn ignore_empty(mut self, ignore_empty: bool) -> Self {
+        self.ignore_empty = ignore_empty;
+        self
+    }
+
+    /// Sets how whitespace between elements is handled.
+    pub fn whitespace(mut self, whitespace: Whitespace) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Sets whether to pad each block's content with leading newlines so
+    /// its compiled output's line numbers line up with the original SFC
+    /// source, instead of restarting from line 1.
+    pub fn pad(mut self, pad: bool) -> Self {
+        self.pad = pad;
+        self
+    }
+}
@@ -1,6 +1,7 @@
 //! Parse output type for SFC parsing.
 
 use super::descriptor::Descriptor;
+use super::diagnostic::{collect_diagnostics, Diagnostic, DiagnosticFfi, Severity};
 use super::handle::Handle;
 use crate::ffi::{self, HermesHandle, HermesRuntime};
 use crate::util::ptr_to_str;
@@ -45,4 +46,46 @@ impl<'c> ParseOutput<'c> {
     pub fn errors(&self) -> impl Iterator<Item = &str> {
         (0..self.error_count()).map(move |i| self.error_message(i))
     }
+
+    /// Get structured diagnostics (message, code, severity, and source span)
+    /// for every parse error and warning. [`Self::error_message`] and
+    /// [`Self::errors`] remain available for callers that only need the
+    /// message text.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        unsafe {
+            collect_diagnostics(
+                *self.0.runtime(),
+                self.0.raw(),
+                self.error_count(),
+                &DiagnosticFfi {
+                    message: ffi::vue_parse_result_error_message,
+                    code: ffi::vue_parse_result_error_code,
+                    is_warning: ffi::vue_parse_result_error_is_warning,
+                    has_loc: ffi::vue_parse_result_error_has_loc,
+                    loc_start_offset: ffi::vue_parse_result_error_loc_start_offset,
+                    loc_start_line: ffi::vue_parse_result_error_loc_start_line,
+                    loc_start_column: ffi::vue_parse_result_error_loc_start_column,
+                    loc_end_offset: ffi::vue_parse_result_error_loc_end_offset,
+                    loc_end_line: ffi::vue_parse_result_error_loc_end_line,
+                    loc_end_column: ffi::vue_parse_result_error_loc_end_column,
+                },
+            )
+        }
+    }
+
+    /// The subset of [`Self::diagnostics`] with [`Severity::Error`].
+    pub fn error_diagnostics(&self) -> Vec<Diagnostic> {
+        self.diagnostics()
+            .into_iter()
+            .filter(|d| d.severity == Severity::Error)
+            .collect()
+    }
+
+    /// The subset of [`Self::diagnostics`] with [`Severity::Warning`].
+    pub fn warning_diagnostics(&self) -> Vec<Diagnostic> {
+        self.diagnostics()
+            .into_iter()
+            .filter(|d| d.severity == Severity::Warning)
+            .collect()
+    }
 }
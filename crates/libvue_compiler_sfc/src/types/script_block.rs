@@ -4,10 +4,11 @@ use std::collections::HashMap;
 
 use super::attr_value::AttrValue;
 use super::custom_block::{get_block_attrs, get_block_loc};
+use super::diagnostic::{collect_diagnostics, Diagnostic, DiagnosticFfi};
 use super::handle::Handle;
 use super::import_binding::ImportBinding;
 use super::source_location::SourceLocation;
-use crate::ffi;
+use crate::ffi::{self, HermesHandle, HermesRuntime};
 use crate::util::ptr_to_str;
 
 /// Script block from an SFC.
@@ -173,6 +174,31 @@ impl ScriptBlock<'_> {
         warnings
     }
 
+    /// Get the setup-macro warnings as span-labeled diagnostics, so callers
+    /// can point at the offending code instead of only printing the
+    /// message. [`Self::warnings`] remains available for plain strings.
+    pub fn warning_diagnostics(&self) -> Vec<Diagnostic> {
+        unsafe {
+            collect_diagnostics(
+                *self.0.runtime(),
+                self.0.raw(),
+                self.warnings_count(),
+                &DiagnosticFfi {
+                    message: ffi::vue_script_warning_at,
+                    code: ffi::vue_script_warning_code,
+                    is_warning: always_warning,
+                    has_loc: ffi::vue_script_warning_has_loc,
+                    loc_start_offset: ffi::vue_script_warning_loc_start_offset,
+                    loc_start_line: ffi::vue_script_warning_loc_start_line,
+                    loc_start_column: ffi::vue_script_warning_loc_start_column,
+                    loc_end_offset: ffi::vue_script_warning_loc_end_offset,
+                    loc_end_line: ffi::vue_script_warning_loc_end_line,
+                    loc_end_column: ffi::vue_script_warning_loc_end_column,
+                },
+            )
+        }
+    }
+
     /// Get the number of dependencies in the script block.
     pub fn deps_count(&self) -> usize {
         unsafe { ffi::vue_script_deps_count(*self.0.runtime(), self.0.raw()) }
@@ -193,3 +219,10 @@ impl ScriptBlock<'_> {
         deps
     }
 }
+
+/// Setup-macro warnings have no error/warning flag of their own - they are
+/// always warnings - so this just satisfies [`DiagnosticFfi::is_warning`]'s
+/// shape without a real FFI call.
+unsafe extern "C" fn always_warning(_rt: HermesRuntime, _handle: HermesHandle, _index: usize) -> bool {
+    true
+}
@@ -0,0 +1,70 @@
+//! Builder-style options for [`crate::Descriptor::compile_script_with`].
+
+/// Fluent builder for [`crate::Descriptor::compile_script_with`], mirroring
+/// [`crate::TemplateOptions`]/[`crate::StyleOptions`].
+#[derive(Debug, Clone)]
+pub struct ScriptOptions {
+    pub(crate) scope_id: String,
+    pub(crate) prod: bool,
+    pub(crate) source_map: bool,
+    pub(crate) inline_template: bool,
+    pub(crate) ts: bool,
+    pub(crate) hmr: bool,
+}
+
+impl Default for ScriptOptions {
+    fn default() -> Self {
+        Self {
+            scope_id: String::new(),
+            prod: false,
+            source_map: false,
+            inline_template: false,
+            ts: false,
+            hmr: false,
+        }
+    }
+}
+
+impl ScriptOptions {
+    /// Starts a new builder with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the scope ID for scoped CSS (e.g. `"data-v-abc123"`).
+    pub fn scope_id(mut self, scope_id: impl Into<String>) -> Self {
+        self.scope_id = scope_id.into();
+        self
+    }
+
+    /// Sets whether to compile for production (drops dev-only checks).
+    pub fn prod(mut self, prod: bool) -> Self {
+        self.prod = prod;
+        self
+    }
+
+    /// Sets whether to generate a source map for the compiled output.
+    pub fn source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    /// Sets whether to inline the template's render function directly into
+    /// `<script setup>` rather than compiling it separately.
+    pub fn inline_template(mut self, inline_template: bool) -> Self {
+        self.inline_template = inline_template;
+        self
+    }
+
+    /// Sets whether to parse the script as TypeScript.
+    pub fn ts(mut self, ts: bool) -> Self {
+        self.ts = ts;
+        self
+    }
+
+    /// Sets whether to emit `<script setup>` HMR rerender helpers.
+    pub fn hmr(mut self, hmr: bool) -> Self {
+        self.hmr = hmr;
+        self
+    }
+}
@@ -1,7 +1,10 @@
 //! Script compilation output type.
 
+use super::diagnostic::{collect_diagnostics, Diagnostic, DiagnosticFfi};
 use super::handle::Handle;
 use crate::ffi::{self, HermesHandle};
+use crate::source_map::SourceMap;
+use crate::types::Result;
 use crate::util::ptr_to_str;
 
 /// Output of compiling script blocks.
@@ -26,4 +29,75 @@ impl<'c> ScriptOutput<'c> {
     pub(crate) fn bindings_handle(&self) -> HermesHandle {
         unsafe { ffi::vue_script_result_bindings(*self.0.runtime(), self.0.raw()) }
     }
+
+    /// Get the injected `useCssVars` setup-hook call (e.g.
+    /// `_useCssVars(_ctx => ({ "<hash>-background": (_unref(background)) }))`)
+    /// that binds [`crate::StyleOutput::css_var_map`]'s custom properties to
+    /// their reactive source expressions, if this component uses any
+    /// `v-bind()` in a scoped style. `None` if there were no CSS variables
+    /// to inject.
+    pub fn use_css_vars_injection(&self) -> Option<&str> {
+        let code = unsafe {
+            ptr_to_str(ffi::vue_script_result_use_css_vars_code(
+                *self.0.runtime(),
+                self.0.raw(),
+            ))
+        };
+        if code.is_empty() {
+            None
+        } else {
+            Some(code)
+        }
+    }
+
+    /// Get the Source Map V3 JSON produced for this script, if source maps
+    /// were enabled for the compile. Borrows directly from the Hermes
+    /// handle, so it's only valid as long as this `ScriptOutput` is.
+    pub fn source_map(&self) -> Option<&str> {
+        let map = unsafe { ptr_to_str(ffi::vue_script_result_map(*self.0.runtime(), self.0.raw())) };
+        if map.is_empty() {
+            None
+        } else {
+            Some(map)
+        }
+    }
+
+    /// Get the Source Map V3 document produced for this script, typed
+    /// rather than raw JSON, if source maps were enabled for the compile.
+    pub fn parsed_source_map(&self) -> Result<Option<SourceMap>> {
+        self.source_map().map(SourceMap::parse).transpose()
+    }
+
+    /// Get the number of compilation errors.
+    pub fn error_count(&self) -> usize {
+        unsafe { ffi::vue_script_result_error_count(*self.0.runtime(), self.0.raw()) }
+    }
+
+    /// Check if compilation produced errors.
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    /// Get structured diagnostics for every compilation error.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        unsafe {
+            collect_diagnostics(
+                *self.0.runtime(),
+                self.0.raw(),
+                self.error_count(),
+                &DiagnosticFfi {
+                    message: ffi::vue_script_result_error_message,
+                    code: ffi::vue_script_result_error_code,
+                    is_warning: ffi::vue_script_result_error_is_warning,
+                    has_loc: ffi::vue_script_result_error_has_loc,
+                    loc_start_offset: ffi::vue_script_result_error_loc_start_offset,
+                    loc_start_line: ffi::vue_script_result_error_loc_start_line,
+                    loc_start_column: ffi::vue_script_result_error_loc_start_column,
+                    loc_end_offset: ffi::vue_script_result_error_loc_end_offset,
+                    loc_end_line: ffi::vue_script_result_error_loc_end_line,
+                    loc_end_column: ffi::vue_script_result_error_loc_end_column,
+                },
+            )
+        }
+    }
 }
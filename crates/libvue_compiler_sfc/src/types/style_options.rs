@@ -0,0 +1,61 @@
+//! Builder-style options for [`crate::Compiler::compile_style_with`].
+
+/// Fluent builder for [`crate::Compiler::compile_style_with`], mirroring
+/// [`crate::TemplateOptions`].
+#[derive(Debug, Clone)]
+pub struct StyleOptions {
+    pub(crate) filename: String,
+    pub(crate) scope_id: String,
+    pub(crate) scoped: bool,
+    pub(crate) prod: bool,
+    pub(crate) source_map: bool,
+}
+
+impl Default for StyleOptions {
+    fn default() -> Self {
+        Self {
+            filename: String::new(),
+            scope_id: String::new(),
+            scoped: false,
+            prod: false,
+            source_map: false,
+        }
+    }
+}
+
+impl StyleOptions {
+    /// Starts a new builder with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the filename used for error messages and source maps.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = filename.into();
+        self
+    }
+
+    /// Sets the scope ID for scoped CSS (e.g. `"data-v-abc123"`).
+    pub fn scope_id(mut self, scope_id: impl Into<String>) -> Self {
+        self.scope_id = scope_id.into();
+        self
+    }
+
+    /// Sets whether to add scoped attribute selectors.
+    pub fn scoped(mut self, scoped: bool) -> Self {
+        self.scoped = scoped;
+        self
+    }
+
+    /// Sets whether to compile for production (drops dev-only checks).
+    pub fn prod(mut self, prod: bool) -> Self {
+        self.prod = prod;
+        self
+    }
+
+    /// Sets whether to generate a source map for the compiled output.
+    pub fn source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
@@ -1,9 +1,28 @@
 //! Style compilation output type.
 
+use std::collections::HashMap;
+
+use super::diagnostic::{collect_diagnostics, Diagnostic, DiagnosticFfi};
 use super::handle::Handle;
 use crate::ffi::{self, HermesHandle, HermesRuntime};
+use crate::source_map::SourceMap;
+use crate::types::Result;
 use crate::util::ptr_to_str;
 
+/// Owned, handle-free snapshot of a [`StyleOutput`], for storing in a
+/// [`crate::CompileCache`] or otherwise outliving the `Compiler` that
+/// produced it.
+#[derive(Debug, Clone)]
+pub struct StyleSnapshot {
+    /// The compiled CSS.
+    pub code: String,
+    /// The Source Map V3 JSON produced for this style block, if source maps
+    /// were enabled.
+    pub source_map: Option<String>,
+    /// Structured diagnostics for every compilation error.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
 /// Output of compiling a style block.
 pub struct StyleOutput<'c>(Handle<'c>);
 
@@ -16,4 +35,98 @@ impl<'c> StyleOutput<'c> {
     pub fn code(&self) -> &str {
         unsafe { ptr_to_str(ffi::vue_style_result_code(*self.0.runtime(), self.0.raw())) }
     }
+
+    /// Get the Source Map V3 JSON produced for this style block, if source
+    /// maps were enabled for the compile. Borrows directly from the Hermes
+    /// handle, so it's only valid as long as this `StyleOutput` is.
+    pub fn source_map(&self) -> Option<&str> {
+        let map = unsafe { ptr_to_str(ffi::vue_style_result_map(*self.0.runtime(), self.0.raw())) };
+        if map.is_empty() {
+            None
+        } else {
+            Some(map)
+        }
+    }
+
+    /// Get the Source Map V3 document produced for this style block, typed
+    /// rather than raw JSON, if source maps were enabled for the compile.
+    pub fn parsed_source_map(&self) -> Result<Option<SourceMap>> {
+        self.source_map().map(SourceMap::parse).transpose()
+    }
+
+    /// Get the number of `v-bind()` CSS variables rewritten in this style
+    /// block.
+    pub fn css_var_map_count(&self) -> usize {
+        unsafe { ffi::vue_style_result_css_var_map_count(*self.0.runtime(), self.0.raw()) }
+    }
+
+    /// Get the `v-bind(expr)` -> generated `--<hash>-<name>` custom
+    /// property mapping for this style block, so callers can correlate a
+    /// CSS custom property back to the reactive expression it was
+    /// rewritten from.
+    pub fn css_var_map(&self) -> HashMap<String, String> {
+        let count = self.css_var_map_count();
+        let rt = *self.0.runtime();
+        let mut map = HashMap::with_capacity(count);
+
+        for i in 0..count {
+            let expr = unsafe {
+                ptr_to_str(ffi::vue_style_result_css_var_map_key_at(rt, self.0.raw(), i))
+                    .to_string()
+            };
+            let property = unsafe {
+                ptr_to_str(ffi::vue_style_result_css_var_map_value_at(
+                    rt,
+                    self.0.raw(),
+                    i,
+                ))
+                .to_string()
+            };
+            map.insert(expr, property);
+        }
+
+        map
+    }
+
+    /// Get the number of compilation errors.
+    pub fn error_count(&self) -> usize {
+        unsafe { ffi::vue_style_result_error_count(*self.0.runtime(), self.0.raw()) }
+    }
+
+    /// Check if compilation produced errors.
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    /// Get structured diagnostics for every compilation error.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        unsafe {
+            collect_diagnostics(
+                *self.0.runtime(),
+                self.0.raw(),
+                self.error_count(),
+                &DiagnosticFfi {
+                    message: ffi::vue_style_result_error_message,
+                    code: ffi::vue_style_result_error_code,
+                    is_warning: ffi::vue_style_result_error_is_warning,
+                    has_loc: ffi::vue_style_result_error_has_loc,
+                    loc_start_offset: ffi::vue_style_result_error_loc_start_offset,
+                    loc_start_line: ffi::vue_style_result_error_loc_start_line,
+                    loc_start_column: ffi::vue_style_result_error_loc_start_column,
+                    loc_end_offset: ffi::vue_style_result_error_loc_end_offset,
+                    loc_end_line: ffi::vue_style_result_error_loc_end_line,
+                    loc_end_column: ffi::vue_style_result_error_loc_end_column,
+                },
+            )
+        }
+    }
+
+    /// Takes an owned, handle-free snapshot of this output.
+    pub fn snapshot(&self) -> StyleSnapshot {
+        StyleSnapshot {
+            code: self.code().to_string(),
+            source_map: self.source_map().map(str::to_string),
+            diagnostics: self.diagnostics(),
+        }
+    }
 }
@@ -0,0 +1,116 @@
+//! Builder-style options for [`crate::Compiler::compile_template_with`].
+
+use super::script_output::ScriptOutput;
+
+/// How whitespace between elements is handled during template compilation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Whitespace {
+    /// Collapse and trim whitespace between elements (the default).
+    #[default]
+    Condense,
+    /// Keep whitespace between elements exactly as written.
+    Preserve,
+}
+
+/// Fluent builder for [`crate::Compiler::compile_template_with`], following
+/// the options-struct pattern Rhai's `Engine::call_fn_with_options` uses in
+/// place of a long, hard-to-extend positional argument list.
+///
+/// # Example
+///
+/// ```ignore
+/// let options = TemplateOptions::new().scope_id("data-v-abc123").scoped(true).ssr(true);
+/// compiler.compile_template_with(source, &options)?;
+/// ```
+pub struct TemplateOptions<'c> {
+    pub(crate) filename: String,
+    pub(crate) scope_id: String,
+    pub(crate) scoped: bool,
+    pub(crate) ssr: bool,
+    pub(crate) prod: bool,
+    pub(crate) source_map: bool,
+    pub(crate) whitespace: Whitespace,
+    pub(crate) inline_template: bool,
+    pub(crate) bindings: Option<&'c ScriptOutput<'c>>,
+}
+
+impl<'c> Default for TemplateOptions<'c> {
+    fn default() -> Self {
+        Self {
+            filename: String::new(),
+            scope_id: String::new(),
+            scoped: false,
+            ssr: false,
+            prod: false,
+            source_map: false,
+            whitespace: Whitespace::default(),
+            inline_template: false,
+            bindings: None,
+        }
+    }
+}
+
+impl<'c> TemplateOptions<'c> {
+    /// Starts a new builder with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the filename used for error messages and source maps.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = filename.into();
+        self
+    }
+
+    /// Sets the scope ID for scoped CSS (e.g. `"data-v-abc123"`).
+    pub fn scope_id(mut self, scope_id: impl Into<String>) -> Self {
+        self.scope_id = scope_id.into();
+        self
+    }
+
+    /// Sets whether to add scoped attribute selectors.
+    pub fn scoped(mut self, scoped: bool) -> Self {
+        self.scoped = scoped;
+        self
+    }
+
+    /// Sets whether to compile for server-side rendering.
+    pub fn ssr(mut self, ssr: bool) -> Self {
+        self.ssr = ssr;
+        self
+    }
+
+    /// Sets whether to compile for production (drops dev-only checks).
+    pub fn prod(mut self, prod: bool) -> Self {
+        self.prod = prod;
+        self
+    }
+
+    /// Sets whether to generate a source map for the compiled output.
+    pub fn source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    /// Sets how whitespace between elements is handled.
+    pub fn whitespace(mut self, whitespace: Whitespace) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Sets whether this template is being compiled inline (e.g. from
+    /// `<script setup>`'s inline template), which affects binding
+    /// resolution.
+    pub fn inline_template(mut self, inline_template: bool) -> Self {
+        self.inline_template = inline_template;
+        self
+    }
+
+    /// Sets the script compilation output to resolve bindings against, for
+    /// the same optimizations `Compiler::compile_template`'s `bindings`
+    /// argument enables.
+    pub fn bindings(mut self, bindings: &'c ScriptOutput<'c>) -> Self {
+        self.bindings = Some(bindings);
+        self
+    }
+}
@@ -1,9 +1,26 @@
 //! Template compilation output type.
 
+use super::diagnostic::{collect_diagnostics, Diagnostic, DiagnosticFfi};
 use super::handle::Handle;
 use crate::ffi::{self, HermesHandle, HermesRuntime};
+use crate::source_map::SourceMap;
+use crate::types::Result;
 use crate::util::ptr_to_str;
 
+/// Owned, handle-free snapshot of a [`TemplateOutput`], for storing in a
+/// [`crate::CompileCache`] or otherwise outliving the `Compiler` that
+/// produced it.
+#[derive(Debug, Clone)]
+pub struct TemplateSnapshot {
+    /// The compiled render function code.
+    pub code: String,
+    /// The Source Map V3 JSON produced for this template, if source maps
+    /// were enabled.
+    pub source_map: Option<String>,
+    /// Structured diagnostics for every compilation error.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
 /// Output of compiling a template.
 pub struct TemplateOutput<'c>(Handle<'c>);
 
@@ -31,4 +48,55 @@ impl<'c> TemplateOutput<'c> {
     pub fn has_errors(&self) -> bool {
         self.error_count() > 0
     }
+
+    /// Get the Source Map V3 JSON produced for this template, if source maps
+    /// were enabled for the compile. Borrows directly from the Hermes
+    /// handle, so it's only valid as long as this `TemplateOutput` is.
+    pub fn source_map(&self) -> Option<&str> {
+        let map =
+            unsafe { ptr_to_str(ffi::vue_template_result_map(*self.0.runtime(), self.0.raw())) };
+        if map.is_empty() {
+            None
+        } else {
+            Some(map)
+        }
+    }
+
+    /// Get the Source Map V3 document produced for this template, typed
+    /// rather than raw JSON, if source maps were enabled for the compile.
+    pub fn parsed_source_map(&self) -> Result<Option<SourceMap>> {
+        self.source_map().map(SourceMap::parse).transpose()
+    }
+
+    /// Get structured diagnostics for every compilation error.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        unsafe {
+            collect_diagnostics(
+                *self.0.runtime(),
+                self.0.raw(),
+                self.error_count(),
+                &DiagnosticFfi {
+                    message: ffi::vue_template_result_error_message,
+                    code: ffi::vue_template_result_error_code,
+                    is_warning: ffi::vue_template_result_error_is_warning,
+                    has_loc: ffi::vue_template_result_error_has_loc,
+                    loc_start_offset: ffi::vue_template_result_error_loc_start_offset,
+                    loc_start_line: ffi::vue_template_result_error_loc_start_line,
+                    loc_start_column: ffi::vue_template_result_error_loc_start_column,
+                    loc_end_offset: ffi::vue_template_result_error_loc_end_offset,
+                    loc_end_line: ffi::vue_template_result_error_loc_end_line,
+                    loc_end_column: ffi::vue_template_result_error_loc_end_column,
+                },
+            )
+        }
+    }
+
+    /// Takes an owned, handle-free snapshot of this output.
+    pub fn snapshot(&self) -> TemplateSnapshot {
+        TemplateSnapshot {
+            code: self.code().to_string(),
+            source_map: self.source_map().map(str::to_string),
+            diagnostics: self.diagnostics(),
+        }
+    }
 }
@@ -0,0 +1,15 @@
+//! Shared helpers for the safe bindings layer.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// Converts a C string pointer returned by the FFI layer into a `&str`.
+///
+/// Returns an empty string for a null pointer or invalid UTF-8, mirroring
+/// how the rest of the bindings treat "absent" string fields.
+pub(crate) unsafe fn ptr_to_str<'a>(ptr: *const c_char) -> &'a str {
+    if ptr.is_null() {
+        return "";
+    }
+    CStr::from_ptr(ptr).to_str().unwrap_or("")
+}